@@ -1,19 +1,61 @@
 use crate::ast::{Expression, Scope, Value, EvaluationError};
 use std::rc::Rc;
 
+/// Argument of a call, named arguments `f(name: value)` come after positional ones.
+/// Spread `f(...xs)` passes elements of an iterable as positional arguments.
+pub enum Argument {
+    Positional(Rc<dyn Expression>),
+    Spread(Rc<dyn Expression>),
+    Named(String, Rc<dyn Expression>),
+}
+
+/// Positional and named argument values of a call
+pub type EvaluatedArguments = (Vec<Rc<dyn Value>>, Vec<(String, Rc<dyn Value>)>);
+
+/// Evaluates arguments in order, spread arguments are expanded to positional ones
+pub fn evaluate_arguments(args: &[Argument], scope: &mut Scope) -> Result<EvaluatedArguments, EvaluationError> {
+    let mut positional: Vec<Rc<dyn Value>> = vec![];
+    let mut named: Vec<(String, Rc<dyn Value>)> = vec![];
+    for arg in args {
+        match arg {
+            Argument::Positional(expression) => positional.push(expression.evaluate(scope)?),
+            Argument::Spread(expression) => positional.extend(expression.evaluate(scope)?.iterate()?),
+            Argument::Named(name, expression) => named.push((name.clone(), expression.evaluate(scope)?)),
+        }
+    }
+    Ok((positional, named))
+}
+
+/// Prints arguments of a call or method call at given level
+pub fn visualize_arguments(args: &[Argument], level: usize) {
+    for arg in args {
+        match arg {
+            Argument::Positional(expression) => expression.visualize(level),
+            Argument::Spread(expression) => {
+                println!("{} Spread", "-".repeat(level));
+                expression.visualize(level + 1)
+            }
+            Argument::Named(name, expression) => {
+                println!("{} Named({})", "-".repeat(level), name);
+                expression.visualize(level + 1)
+            }
+        }
+    }
+}
+
 pub struct CallExpression {
     target: Rc<dyn Expression>,
-    args: Vec<Rc<dyn Expression>>,
+    args: Vec<Argument>,
 }
 
 impl CallExpression {
-    pub fn new(identifier: Rc<dyn Expression>, args: Vec<Rc<dyn Expression>>) -> CallExpression {
+    pub fn new(identifier: Rc<dyn Expression>, args: Vec<Argument>) -> CallExpression {
         CallExpression {
             target: identifier,
             args,
         }
     }
-    pub fn rc(identifier: Rc<dyn Expression>, args: Vec<Rc<dyn Expression>>) -> Rc<CallExpression> {
+    pub fn rc(identifier: Rc<dyn Expression>, args: Vec<Argument>) -> Rc<CallExpression> {
         Rc::new(CallExpression::new(identifier, args))
     }
 }
@@ -21,18 +63,13 @@ impl CallExpression {
 impl Expression for CallExpression {
     fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
         let target_expr = self.target.evaluate(scope)?;
-        let mut evaled: Vec<Rc<dyn Value>> = vec![];
-        for i in &self.args {
-            evaled.push(i.evaluate(scope)?)
-        }
-        target_expr.call(scope, evaled)
+        let (evaled, named) = evaluate_arguments(&self.args, scope)?;
+        target_expr.call(scope, evaled, named)
     }
 
     fn visualize(&self, level: usize) {
         println!("{} CallExpression", "-".repeat(level));
-        for a in &self.args {
-            a.visualize(level+1)
-        }
+        visualize_arguments(&self.args, level + 1);
     }
 }
 
@@ -42,7 +79,7 @@ mod tests {
     use super::*;
     use crate::ast::tests::{evaluates_to, errors_to, evaluates_to_void};
     use crate::ast::v_integer::{IntegerValue, IntegerExpression};
-    use crate::ast::s_fun::FunStatement;
+    use crate::ast::s_fun::{FunStatement, Parameter};
     use crate::ast::e_identifier::IdentifierExpression;
 
     #[test]
@@ -64,7 +101,8 @@ mod tests {
             FunStatement::new(
                 "foo".to_string(),
                 vec![],
-                IntegerExpression::rc(123))
+                IntegerExpression::rc(123),
+                None)
                 .evaluate(&mut scope)
         );
 
@@ -89,8 +127,9 @@ mod tests {
         evaluates_to_void(
             FunStatement::new(
                 "foo".to_string(),
-                vec![IdentifierExpression::new("a".to_string())],
-                IntegerExpression::rc(123))
+                vec![Parameter::new("a".to_string(), None)],
+                IntegerExpression::rc(123),
+                None)
                 .evaluate(&mut scope)
         );
 
@@ -98,14 +137,14 @@ mod tests {
             CallExpression::new(
                 IdentifierExpression::rc("foo".to_string()),
                 vec![]).evaluate(&mut scope),
-            "Expecting 1 arguments for call but 0 given",
+            "Missing argument for parameter `a`",
         );
 
         evaluates_to(
             CallExpression::new(
                 IdentifierExpression::rc("foo".to_string()),
                 vec![
-                    IntegerExpression::rc(1)
+                    Argument::Positional(IntegerExpression::rc(1))
                 ]).evaluate(&mut scope),
             IntegerValue::rc_value(123),
         );
@@ -114,8 +153,8 @@ mod tests {
             CallExpression::new(
                 IdentifierExpression::rc("foo".to_string()),
                 vec![
-                    IntegerExpression::rc(1),
-                    IntegerExpression::rc(1)
+                    Argument::Positional(IntegerExpression::rc(1)),
+                    Argument::Positional(IntegerExpression::rc(1))
                 ]).evaluate(&mut scope),
             "Expecting 1 arguments for call but 2 given",
         );
@@ -0,0 +1,52 @@
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ComparisonOperator {
+    LessThan,
+    GreaterThan,
+    LessOrEquals,
+    GreaterOrEquals,
+}
+
+pub struct ComparisonExpression {
+    operator: ComparisonOperator,
+    left: Rc<dyn Expression>,
+    right: Rc<dyn Expression>,
+}
+
+impl ComparisonExpression {
+    pub fn new(operator: ComparisonOperator, left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> ComparisonExpression {
+        ComparisonExpression {
+            operator,
+            left,
+            right,
+        }
+    }
+    pub fn rc(operator: ComparisonOperator, left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> Rc<ComparisonExpression> {
+        Rc::new(ComparisonExpression::new(operator, left, right))
+    }
+}
+
+impl Expression for ComparisonExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let l_value = self.left.evaluate(scope)?;
+        let r_value = self.right.evaluate(scope)?;
+
+        match self.operator {
+            ComparisonOperator::LessThan => l_value.apply_less_than(r_value),
+            ComparisonOperator::GreaterThan => l_value.apply_greater_than(r_value),
+            ComparisonOperator::LessOrEquals => l_value.apply_less_or_equals(r_value),
+            ComparisonOperator::GreaterOrEquals => l_value.apply_greater_or_equals(r_value),
+        }
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} ComparisonExpression ({:?})", "-".repeat(level), self.operator);
+        println!("{} Left", "-".repeat(level + 1));
+        self.left.visualize(level + 2);
+        println!("{} Right", "-".repeat(level + 1));
+        self.right.visualize(level + 2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+
+pub struct DivisionExpression {
+    left: Rc<dyn Expression>,
+    right: Rc<dyn Expression>,
+}
+
+impl DivisionExpression {
+    pub fn new(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> DivisionExpression {
+        DivisionExpression {
+            left,
+            right,
+        }
+    }
+    pub fn rc(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> Rc<DivisionExpression> {
+        Rc::new(DivisionExpression::new(left, right))
+    }
+}
+
+impl Expression for DivisionExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let l_value = self.left.evaluate(scope)?;
+        let r_value = self.right.evaluate(scope)?;
+
+        l_value.apply_division(r_value)
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} DivisionExpression", "-".repeat(level));
+        println!("{} Left", "-".repeat(level + 1));
+        self.left.visualize(level + 2);
+        println!("{} Right", "-".repeat(level + 1));
+        self.right.visualize(level + 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+
+    #[test]
+    fn test_division_expression() {
+        let expr = DivisionExpression::new(
+            IntegerExpression::rc(10),
+            IntegerExpression::rc(2),
+        );
+        evaluates_to(
+            expr.evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(5)
+        );
+
+        let expr = DivisionExpression::new(
+            Rc::new(DivisionExpression::new(
+                IntegerExpression::rc(100),
+                IntegerExpression::rc(5),
+            )),
+            Rc::new(DivisionExpression::new(
+                IntegerExpression::rc(9),
+                IntegerExpression::rc(2),
+            ))
+        );
+        evaluates_to(
+            expr.evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(5)
+        );
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let expr = DivisionExpression::new(
+            IntegerExpression::rc(1),
+            IntegerExpression::rc(0),
+        );
+        errors_to(
+            expr.evaluate(&mut Scope::new()),
+            "Division by zero"
+        );
+    }
+
+}
\ No newline at end of file
@@ -12,12 +12,7 @@ impl IdentifierExpression {
         }
     }
     pub fn rc(value: String) -> Rc<IdentifierExpression> {
-        Rc::new(IdentifierExpression {
-            value,
-        })
-    }
-    pub fn name(&self) -> String {
-        self.value.clone()
+        Rc::new(IdentifierExpression::new(value))
     }
 }
 
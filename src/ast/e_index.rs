@@ -0,0 +1,67 @@
+use crate::ast::{Expression, Value, EvaluationError, IndexAccess};
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+
+pub struct IndexExpression {
+    target: Rc<dyn Expression>,
+    index: Rc<dyn Expression>,
+}
+
+impl IndexExpression {
+    pub fn new(target: Rc<dyn Expression>, index: Rc<dyn Expression>) -> IndexExpression {
+        IndexExpression {
+            target,
+            index,
+        }
+    }
+    pub fn rc(target: Rc<dyn Expression>, index: Rc<dyn Expression>) -> Rc<IndexExpression> {
+        Rc::new(IndexExpression::new(target, index))
+    }
+}
+
+impl Expression for IndexExpression {
+    fn get_index(&self) -> Result<IndexAccess<'_>, EvaluationError> {
+        Ok((&self.target, &self.index))
+    }
+
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let target = self.target.evaluate(scope)?;
+        let index = self.index.evaluate(scope)?;
+
+        target.index(index)
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} IndexExpression", "-".repeat(level));
+        println!("{} Target", "-".repeat(level + 1));
+        self.target.visualize(level + 2);
+        println!("{} Index", "-".repeat(level + 1));
+        self.index.visualize(level + 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+    use crate::ast::v_list::ListExpression;
+
+    #[test]
+    fn test_index_expression() {
+        let list = ListExpression::rc(vec![
+            IntegerExpression::rc(1),
+            IntegerExpression::rc(2),
+        ]);
+        evaluates_to(
+            IndexExpression::new(list.clone(), IntegerExpression::rc(0)).evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(1));
+        errors_to(
+            IndexExpression::new(list, IntegerExpression::rc(3)).evaluate(&mut Scope::new()),
+            "Index 3 out of range for length 2");
+        errors_to(
+            IndexExpression::new(IntegerExpression::rc(1), IntegerExpression::rc(0)).evaluate(&mut Scope::new()),
+            "Integer can't be indexed");
+    }
+}
\ No newline at end of file
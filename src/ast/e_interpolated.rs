@@ -0,0 +1,60 @@
+use crate::ast::{Expression, Scope, Value, EvaluationError};
+use crate::ast::v_string::StringValue;
+use std::rc::Rc;
+
+/// String literal with `${...}` expressions, parts are literals and expressions in order
+pub struct InterpolatedStringExpression {
+    parts: Vec<Rc<dyn Expression>>,
+}
+
+impl InterpolatedStringExpression {
+    pub fn new(parts: Vec<Rc<dyn Expression>>) -> InterpolatedStringExpression {
+        InterpolatedStringExpression {
+            parts,
+        }
+    }
+    pub fn rc(parts: Vec<Rc<dyn Expression>>) -> Rc<InterpolatedStringExpression> {
+        Rc::new(InterpolatedStringExpression::new(parts))
+    }
+}
+
+impl Expression for InterpolatedStringExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let mut result = String::new();
+        for part in &self.parts {
+            // Values are formatted the same way as print does
+            result.push_str(&format!("{}", part.evaluate(scope)?.type_matcher()));
+        }
+        Ok(StringValue::rc_value(result))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} InterpolatedString", "-".repeat(level));
+        for part in &self.parts {
+            part.visualize(level + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::evaluates_to;
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+    use crate::ast::v_string::StringExpression;
+    use crate::ast::e_identifier::IdentifierExpression;
+
+    #[test]
+    fn test_interpolated_string() {
+        let mut scope = Scope::new();
+        scope.store("n".to_string(), IntegerValue::rc_value(5));
+        evaluates_to(
+            InterpolatedStringExpression::new(vec![
+                StringExpression::rc("n=".to_string()),
+                IdentifierExpression::rc("n".to_string()),
+                StringExpression::rc(", one=".to_string()),
+                IntegerExpression::rc(1),
+            ]).evaluate(&mut scope),
+            StringValue::rc_value("n=5, one=1".to_string()));
+    }
+}
\ No newline at end of file
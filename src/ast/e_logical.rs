@@ -0,0 +1,125 @@
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::scope::Scope;
+use crate::ast::v_boolean::BooleanValue;
+use std::rc::Rc;
+
+/// Short circuiting &&, right side is not evaluated if left side is falsy
+pub struct AndExpression {
+    left: Rc<dyn Expression>,
+    right: Rc<dyn Expression>,
+}
+
+impl AndExpression {
+    pub fn new(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> AndExpression {
+        AndExpression {
+            left,
+            right,
+        }
+    }
+    pub fn rc(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> Rc<AndExpression> {
+        Rc::new(AndExpression::new(left, right))
+    }
+}
+
+impl Expression for AndExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        if !self.left.evaluate(scope)?.is_truthy() {
+            return Ok(BooleanValue::rc(false));
+        }
+        Ok(BooleanValue::rc(self.right.evaluate(scope)?.is_truthy()))
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} AndExpression", "-".repeat(level));
+        println!("{} Left", "-".repeat(level + 1));
+        self.left.visualize(level + 2);
+        println!("{} Right", "-".repeat(level + 1));
+        self.right.visualize(level + 2);
+    }
+}
+
+/// Short circuiting ||, right side is not evaluated if left side is truthy
+pub struct OrExpression {
+    left: Rc<dyn Expression>,
+    right: Rc<dyn Expression>,
+}
+
+impl OrExpression {
+    pub fn new(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> OrExpression {
+        OrExpression {
+            left,
+            right,
+        }
+    }
+    pub fn rc(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> Rc<OrExpression> {
+        Rc::new(OrExpression::new(left, right))
+    }
+}
+
+impl Expression for OrExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        if self.left.evaluate(scope)?.is_truthy() {
+            return Ok(BooleanValue::rc(true));
+        }
+        Ok(BooleanValue::rc(self.right.evaluate(scope)?.is_truthy()))
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} OrExpression", "-".repeat(level));
+        println!("{} Left", "-".repeat(level + 1));
+        self.left.visualize(level + 2);
+        println!("{} Right", "-".repeat(level + 1));
+        self.right.visualize(level + 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::evaluates_to;
+    use crate::ast::v_boolean::BooleanExpression;
+    use crate::ast::e_identifier::IdentifierExpression;
+
+    #[test]
+    fn test_and_expression() {
+        evaluates_to(
+            AndExpression::new(BooleanExpression::rc(true), BooleanExpression::rc(true))
+                .evaluate(&mut Scope::new()),
+            BooleanValue::rc(true)
+        );
+        evaluates_to(
+            AndExpression::new(BooleanExpression::rc(true), BooleanExpression::rc(false))
+                .evaluate(&mut Scope::new()),
+            BooleanValue::rc(false)
+        );
+        // Right side would fail to resolve if it was evaluated
+        evaluates_to(
+            AndExpression::new(BooleanExpression::rc(false), IdentifierExpression::rc("foo".to_string()))
+                .evaluate(&mut Scope::new()),
+            BooleanValue::rc(false)
+        );
+    }
+
+    #[test]
+    fn test_or_expression() {
+        evaluates_to(
+            OrExpression::new(BooleanExpression::rc(false), BooleanExpression::rc(false))
+                .evaluate(&mut Scope::new()),
+            BooleanValue::rc(false)
+        );
+        evaluates_to(
+            OrExpression::new(BooleanExpression::rc(false), BooleanExpression::rc(true))
+                .evaluate(&mut Scope::new()),
+            BooleanValue::rc(true)
+        );
+        // Right side would fail to resolve if it was evaluated
+        evaluates_to(
+            OrExpression::new(BooleanExpression::rc(true), IdentifierExpression::rc("foo".to_string()))
+                .evaluate(&mut Scope::new()),
+            BooleanValue::rc(true)
+        );
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use crate::ast::{Expression, Value, EvaluationError, MemberAccess};
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+
+pub struct MemberExpression {
+    target: Rc<dyn Expression>,
+    name: String,
+}
+
+impl MemberExpression {
+    pub fn new(target: Rc<dyn Expression>, name: String) -> MemberExpression {
+        MemberExpression {
+            target,
+            name,
+        }
+    }
+    pub fn rc(target: Rc<dyn Expression>, name: String) -> Rc<MemberExpression> {
+        Rc::new(MemberExpression::new(target, name))
+    }
+}
+
+impl Expression for MemberExpression {
+    fn get_member_access(&self) -> Result<MemberAccess<'_>, EvaluationError> {
+        Ok((&self.target, &self.name))
+    }
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        self.target.evaluate(scope)?.get_member(&self.name)
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} MemberExpression ({})", "-".repeat(level), self.name);
+        self.target.visualize(level + 1);
+    }
+}
\ No newline at end of file
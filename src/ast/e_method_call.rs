@@ -0,0 +1,38 @@
+use crate::ast::{Expression, Scope, Value, EvaluationError};
+use crate::ast::e_call::{Argument, evaluate_arguments, visualize_arguments};
+use std::rc::Rc;
+
+pub struct MethodCallExpression {
+    target: Rc<dyn Expression>,
+    name: String,
+    args: Vec<Argument>,
+}
+
+impl MethodCallExpression {
+    pub fn new(target: Rc<dyn Expression>, name: String, args: Vec<Argument>) -> MethodCallExpression {
+        MethodCallExpression {
+            target,
+            name,
+            args,
+        }
+    }
+    pub fn rc(target: Rc<dyn Expression>, name: String, args: Vec<Argument>) -> Rc<MethodCallExpression> {
+        Rc::new(MethodCallExpression::new(target, name, args))
+    }
+}
+
+impl Expression for MethodCallExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let target = self.target.evaluate(scope)?;
+        let (evaled, named) = evaluate_arguments(&self.args, scope)?;
+        target.call_method_named(&self.name, evaled, named)
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} MethodCallExpression ({})", "-".repeat(level), self.name);
+        println!("{} Target", "-".repeat(level + 1));
+        self.target.visualize(level + 2);
+        println!("{} Arguments", "-".repeat(level + 1));
+        visualize_arguments(&self.args, level + 2);
+    }
+}
\ No newline at end of file
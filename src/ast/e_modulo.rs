@@ -0,0 +1,79 @@
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+
+pub struct ModuloExpression {
+    left: Rc<dyn Expression>,
+    right: Rc<dyn Expression>,
+}
+
+impl ModuloExpression {
+    pub fn new(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> ModuloExpression {
+        ModuloExpression {
+            left,
+            right,
+        }
+    }
+    pub fn rc(left: Rc<dyn Expression>, right: Rc<dyn Expression>) -> Rc<ModuloExpression> {
+        Rc::new(ModuloExpression::new(left, right))
+    }
+}
+
+impl Expression for ModuloExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let l_value = self.left.evaluate(scope)?;
+        let r_value = self.right.evaluate(scope)?;
+
+        l_value.apply_modulo(r_value)
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} ModuloExpression", "-".repeat(level));
+        println!("{} Left", "-".repeat(level + 1));
+        self.left.visualize(level + 2);
+        println!("{} Right", "-".repeat(level + 1));
+        self.right.visualize(level + 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+
+    #[test]
+    fn test_modulo_expression() {
+        let expr = ModuloExpression::new(
+            IntegerExpression::rc(10),
+            IntegerExpression::rc(3),
+        );
+        evaluates_to(
+            expr.evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(1)
+        );
+        let expr = ModuloExpression::new(
+            IntegerExpression::rc(-7),
+            IntegerExpression::rc(2),
+        );
+        evaluates_to(
+            expr.evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(-1)
+        );
+    }
+
+    #[test]
+    fn test_modulo_by_zero() {
+        let expr = ModuloExpression::new(
+            IntegerExpression::rc(1),
+            IntegerExpression::rc(0),
+        );
+        errors_to(
+            expr.evaluate(&mut Scope::new()),
+            "Division by zero"
+        );
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::scope::Scope;
+use crate::ast::v_boolean::BooleanValue;
+use std::rc::Rc;
+
+pub struct NotExpression {
+    expression: Rc<dyn Expression>
+}
+
+impl NotExpression {
+    pub fn new(expression: Rc<dyn Expression>) -> NotExpression {
+        NotExpression {
+            expression,
+        }
+    }
+    pub fn rc(expression: Rc<dyn Expression>) -> Rc<NotExpression> {
+        Rc::new(NotExpression::new(expression))
+    }
+}
+
+impl Expression for NotExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(!self.expression.evaluate(scope)?.is_truthy()))
+    }
+
+    fn visualize(&self, level: usize) {
+        println!("{} NotExpression", "-".repeat(level));
+        self.expression.visualize(level + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::evaluates_to;
+    use crate::ast::v_boolean::BooleanExpression;
+    use crate::ast::v_integer::IntegerExpression;
+    use crate::ast::v_null::Null;
+
+    #[test]
+    fn test_not_expression() {
+        evaluates_to(
+            NotExpression::new(BooleanExpression::rc(true)).evaluate(&mut Scope::new()),
+            BooleanValue::rc(false)
+        );
+        evaluates_to(
+            NotExpression::new(BooleanExpression::rc(false)).evaluate(&mut Scope::new()),
+            BooleanValue::rc(true)
+        );
+        evaluates_to(
+            NotExpression::new(IntegerExpression::rc(1)).evaluate(&mut Scope::new()),
+            BooleanValue::rc(false)
+        );
+        evaluates_to(
+            NotExpression::new(Null::rc()).evaluate(&mut Scope::new()),
+            BooleanValue::rc(true)
+        );
+    }
+
+}
\ No newline at end of file
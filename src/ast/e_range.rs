@@ -0,0 +1,76 @@
+use crate::ast::{Expression, Value, EvaluationError, TypeMatcher};
+use crate::ast::scope::Scope;
+use crate::ast::v_range::RangeValue;
+use std::rc::Rc;
+
+pub struct RangeExpression {
+    inclusive: bool,
+    start: Rc<dyn Expression>,
+    end: Rc<dyn Expression>,
+}
+
+impl RangeExpression {
+    pub fn new(inclusive: bool, start: Rc<dyn Expression>, end: Rc<dyn Expression>) -> RangeExpression {
+        RangeExpression {
+            inclusive,
+            start,
+            end,
+        }
+    }
+    pub fn rc(inclusive: bool, start: Rc<dyn Expression>, end: Rc<dyn Expression>) -> Rc<RangeExpression> {
+        Rc::new(RangeExpression::new(inclusive, start, end))
+    }
+}
+
+impl Expression for RangeExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let start = self.start.evaluate(scope)?;
+        let end = self.end.evaluate(scope)?;
+
+        match (start.type_matcher(), end.type_matcher()) {
+            (TypeMatcher::Integer(start), TypeMatcher::Integer(end)) =>
+                Ok(RangeValue::rc_value(*start, *end, self.inclusive)),
+            (start, end) => Err(EvaluationError::operator_not_applicable(
+                if self.inclusive { "..=" } else { ".." },
+                start,
+                end)),
+        }
+    }
+
+    fn visualize(&self, level: usize) {
+        if self.inclusive {
+            println!("{} RangeExpression (inclusive)", "-".repeat(level));
+        } else {
+            println!("{} RangeExpression", "-".repeat(level));
+        }
+        println!("{} Start", "-".repeat(level + 1));
+        self.start.visualize(level + 2);
+        println!("{} End", "-".repeat(level + 1));
+        self.end.visualize(level + 2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::IntegerExpression;
+    use crate::ast::v_string::StringExpression;
+
+    #[test]
+    fn test_range_expression() {
+        evaluates_to(
+            RangeExpression::new(
+                false,
+                IntegerExpression::rc(0),
+                IntegerExpression::rc(5)).evaluate(&mut Scope::new()),
+            RangeValue::rc_value(0, 5, false));
+        errors_to(
+            RangeExpression::new(
+                true,
+                IntegerExpression::rc(0),
+                StringExpression::rc("a".to_string())).evaluate(&mut Scope::new()),
+            "Can't apply Integer ..= String");
+    }
+}
\ No newline at end of file
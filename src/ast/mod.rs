@@ -1,8 +1,18 @@
 use crate::ast::scope::Scope;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::rc::Rc;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::path::PathBuf;
+use crate::ast::v_boolean::BooleanValue;
+use crate::ast::v_integer::IntegerValue;
+use crate::ast::v_string::StringValue;
+use crate::ast::v_null::Null;
+use crate::ast::v_struct::StructValue;
+use crate::lexer::tokens::SourceRef;
 
 pub mod v_integer;
+pub mod v_float;
 pub mod scope;
 pub mod e_plus;
 pub mod e_identifier;
@@ -11,7 +21,10 @@ pub mod e_call;
 pub mod v_void;
 pub mod e_minus;
 pub mod s_let;
+pub mod s_const;
 pub mod e_multiplication;
+pub mod e_division;
+pub mod e_modulo;
 pub mod v_string;
 pub mod v_null;
 pub mod s_grouped;
@@ -19,19 +32,87 @@ pub mod s_return;
 pub mod s_assign;
 pub mod v_boolean;
 pub mod e_equals;
+pub mod e_not;
+pub mod e_logical;
+pub mod e_comparison;
 pub mod s_if;
+pub mod s_while;
+pub mod s_for;
+pub mod v_range;
+pub mod e_range;
+pub mod v_list;
+pub mod e_index;
+pub mod s_index_assign;
+pub mod v_map;
+pub mod e_member;
+pub mod e_method_call;
+pub mod e_interpolated;
+pub mod s_break;
+pub mod s_match;
+pub mod s_struct;
+pub mod v_struct;
+pub mod s_member_assign;
+pub mod s_import;
+pub mod v_module;
+pub mod s_located;
+pub mod s_throw;
+pub mod s_try;
 
-#[derive(Debug)]
 pub struct EvaluationError {
-    msg: String,
+    pub msg: String,
+    /// Where the error happened, set by the innermost statement it passes through
+    location: Option<SourceRef>,
+    /// Value given to `throw`, None for runtime errors
+    thrown: Option<Rc<dyn Value>>,
 }
 
 impl EvaluationError {
     pub fn new(msg: String) -> EvaluationError {
         EvaluationError {
             msg,
+            location: None,
+            thrown: None,
         }
     }
+    pub fn thrown(value: Rc<dyn Value>) -> EvaluationError {
+        EvaluationError {
+            msg: format!("Uncaught exception {}", display_element(&value)),
+            location: None,
+            thrown: Some(value),
+        }
+    }
+    /// Sets location of the error, unless some inner statement already did
+    pub fn at(mut self, location: &SourceRef) -> EvaluationError {
+        if self.location.is_none() {
+            self.location = Some(location.clone());
+        }
+        self
+    }
+    /// Error raised while evaluating imported module, keeps the location inside the module
+    pub fn in_module(self, path: &str) -> EvaluationError {
+        EvaluationError {
+            msg: format!("Can't import \"{}\": {}", path, self.msg),
+            ..self
+        }
+    }
+    /// Value bound by `catch`. Thrown value itself, or
+    /// `Error { message, line, column }` for runtime errors.
+    pub fn to_value(&self) -> Rc<dyn Value> {
+        if let Some(thrown) = &self.thrown {
+            return thrown.clone();
+        }
+        let location = |get: fn(&SourceRef) -> u32| -> Rc<dyn Value> {
+            match &self.location {
+                Some(location) => IntegerValue::rc_value(get(location) as i32),
+                None => Null::rc(),
+            }
+        };
+        StructValue::rc_value("Error".to_string(), vec![
+            ("message".to_string(), StringValue::rc_value(self.msg.clone())),
+            ("line".to_string(), location(|location| location.line)),
+            ("column".to_string(), location(|location| location.column)),
+        ])
+    }
     pub fn not_callable(me: TypeMatcher) -> EvaluationError {
         EvaluationError::new(format!("{} is not callable", me))
     }
@@ -41,6 +122,12 @@ impl EvaluationError {
     pub fn cant_assing(name: &str) -> EvaluationError {
         EvaluationError::new(format!("Can't assing to variable `{}`", name))
     }
+    pub fn cant_assign_constant(name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Can't assign to constant `{}`", name))
+    }
+    pub fn cant_redeclare_constant(name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Can't redeclare constant `{}`", name))
+    }
     pub fn does_not_support_prefix_minus(me: TypeMatcher) -> EvaluationError {
         EvaluationError::new(format!("{} does not support prefix minus", me))
     }
@@ -54,22 +141,126 @@ impl EvaluationError {
                 operator,
                     he_or_she.type_name()))
     }
+    pub fn outside_of_loop(control: LoopControl) -> EvaluationError {
+        EvaluationError::new(format!("`{}` outside of loop", control))
+    }
+    pub fn unknown_label(label: &str) -> EvaluationError {
+        EvaluationError::new(format!("Unknown label `{}`", label))
+    }
+    pub fn not_iterable(me: TypeMatcher) -> EvaluationError {
+        EvaluationError::new(format!("{} is not iterable", me.type_name()))
+    }
+    pub fn not_indexable(me: TypeMatcher) -> EvaluationError {
+        EvaluationError::new(format!("{} can't be indexed", me.type_name()))
+    }
+    pub fn invalid_index(me: TypeMatcher, index: TypeMatcher) -> EvaluationError {
+        EvaluationError::new(format!("Can't index {} with {}", me.type_name(), index.type_name()))
+    }
+    pub fn index_out_of_range(index: i32, length: usize) -> EvaluationError {
+        EvaluationError::new(format!("Index {} out of range for length {}", index, length))
+    }
+    pub fn not_hashable(me: TypeMatcher) -> EvaluationError {
+        EvaluationError::new(format!("{} can't be used as map key", me.type_name()))
+    }
+    pub fn key_not_found(key: &Rc<dyn Value>) -> EvaluationError {
+        EvaluationError::new(format!("Key {} not found", display_element(key)))
+    }
+    pub fn no_match(value: &Rc<dyn Value>) -> EvaluationError {
+        EvaluationError::new(format!("No match arm for {}", display_element(value)))
+    }
+    pub fn no_member(me: TypeMatcher, name: &str) -> EvaluationError {
+        EvaluationError::new(format!("{} has no member `{}`", me.type_name(), name))
+    }
+    pub fn not_a_struct(me: TypeMatcher) -> EvaluationError {
+        EvaluationError::new(format!("{} is not a struct", me.type_name()))
+    }
+    pub fn no_field(me: TypeMatcher, name: &str) -> EvaluationError {
+        EvaluationError::new(format!("{} has no field `{}`", me, name))
+    }
+    pub fn missing_field(me: TypeMatcher, name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Missing field `{}` for {}", name, me))
+    }
+    pub fn duplicate_field(me: TypeMatcher, name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Field `{}` given twice for {}", name, me))
+    }
+    pub fn cant_import(path: &str, reason: &str) -> EvaluationError {
+        EvaluationError::new(format!("Can't import \"{}\": {}", path, reason))
+    }
+    pub fn read_only_module(name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Can't assign to member `{}`, modules are read-only", name))
+    }
+    pub fn import_cycle(cycle: &[PathBuf]) -> EvaluationError {
+        let cycle: Vec<String> = cycle.iter().map(|path| path.display().to_string()).collect();
+        EvaluationError::new(format!("Import cycle: {}", cycle.join(" -> ")))
+    }
+    pub fn division_by_zero() -> EvaluationError {
+        EvaluationError::new("Division by zero".to_string())
+    }
+    pub fn integer_overflow() -> EvaluationError {
+        EvaluationError::new("Integer overflow".to_string())
+    }
     pub fn invalid_arity(expected: usize, received: usize) -> EvaluationError {
         EvaluationError::new(format!("Expecting {} arguments for call but {} given", expected, received))
     }
+    pub fn missing_arguments(parameters: &[&str]) -> EvaluationError {
+        let parameters: Vec<String> = parameters.iter().map(|name| format!("`{}`", name)).collect();
+        match parameters.len() {
+            1 => EvaluationError::new(format!("Missing argument for parameter {}", parameters[0])),
+            _ => EvaluationError::new(format!("Missing arguments for parameters {}", parameters.join(", "))),
+        }
+    }
+    pub fn named_argument_to_method(me: TypeMatcher, method: &str, name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Method `{}` of {} takes no named arguments, `{}` given", method, me.type_name(), name))
+    }
+    pub fn unknown_parameter(name: &str) -> EvaluationError {
+        EvaluationError::new(format!("No parameter named `{}`", name))
+    }
+    pub fn argument_given_twice(name: &str) -> EvaluationError {
+        EvaluationError::new(format!("Argument for parameter `{}` given twice", name))
+    }
+}
+
+/// Arity check for built-in methods
+pub fn check_arity(expected: usize, args: &[Rc<dyn Value>]) -> Result<(), EvaluationError> {
+    if expected != args.len() {
+        return Err(EvaluationError::invalid_arity(expected, args.len()))
+    }
+    Ok(())
 }
 
 impl Display for EvaluationError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "Evaluation Error: {}", self.msg)
+        match &self.location {
+            Some(location) => write!(f, "Evaluation Error: {} @ {}", self.msg, location),
+            None => write!(f, "Evaluation Error: {}", self.msg),
+        }
+    }
+}
+
+impl Debug for EvaluationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvaluationError")
+            .field("msg", &self.msg)
+            .field("location", &self.location)
+            .finish()
     }
 }
 
 #[derive(Debug, PartialEq)]
 pub enum TypeMatcher<'a> {
     Integer(&'a i32),
+    Float(&'a f64),
     String(&'a str),
     Boolean(&'a bool),
+    Range(&'a i32, &'a i32, bool),
+    List(Elements<'a>),
+    Map(Entries<'a>),
+    /// Struct instance with the name of its struct
+    Struct(&'a str, Fields<'a>),
+    /// Struct declaration, `struct Point { x, y }`
+    StructType(&'a str),
+    /// Imported module with the canonical path of its file
+    Module(&'a str),
     Null,
     Void,
     Function,
@@ -79,8 +270,15 @@ impl TypeMatcher<'_> {
     fn type_name(&self) -> &str {
         match self {
             TypeMatcher::Integer(_) => "Integer",
+            TypeMatcher::Float(_) => "Float",
             TypeMatcher::String(_) => "String",
             TypeMatcher::Boolean(_) => "Boolean",
+            TypeMatcher::Range(_, _, _) => "Range",
+            TypeMatcher::List(_) => "List",
+            TypeMatcher::Map(_) => "Map",
+            TypeMatcher::Struct(name, _) => name,
+            TypeMatcher::StructType(_) => "Struct",
+            TypeMatcher::Module(_) => "Module",
             TypeMatcher::Null => "Null",
             TypeMatcher::Void => "Void",
             TypeMatcher::Function => "Fn",
@@ -92,13 +290,226 @@ impl Display for TypeMatcher<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             TypeMatcher::Integer(v) => write!(f, "{}", v),
+            // Debug formatting keeps the fraction (1.0) and round-trips
+            TypeMatcher::Float(v) => write!(f, "{:?}", v),
             TypeMatcher::String(v) => write!(f, "{}", v),
             TypeMatcher::Boolean(v) => write!(f, "{}", v),
+            TypeMatcher::Range(start, end, false) => write!(f, "{}..{}", start, end),
+            TypeMatcher::Range(start, end, true) => write!(f, "{}..={}", start, end),
+            TypeMatcher::List(elements) => write!(f, "[{}]", elements),
+            TypeMatcher::Map(entries) => write!(f, "{{{}}}", entries),
+            TypeMatcher::Struct(name, fields) => write!(f, "{} {{ {} }}", name, fields),
+            TypeMatcher::StructType(name) => write!(f, "struct {}", name),
+            TypeMatcher::Module(path) => write!(f, "module {:?}", path),
             _ => write!(f, "{}", self.type_name())
         }
     }
 }
 
+thread_local! {
+    /// Pairs of collection addresses being compared, or displayed (paired with 0)
+    static VISITING: RefCell<Vec<(usize, usize)>> = const { RefCell::new(vec![]) };
+}
+
+/// Runs `visit` unless the same collections are already being visited further up,
+/// which means a collection contains itself and None is returned instead
+fn visit_once<T>(addresses: (usize, usize), visit: impl FnOnce() -> T) -> Option<T> {
+    if VISITING.with(|visiting| visiting.borrow().contains(&addresses)) {
+        return None;
+    }
+    VISITING.with(|visiting| visiting.borrow_mut().push(addresses));
+    let result = visit();
+    VISITING.with(|visiting| visiting.borrow_mut().pop());
+    Some(result)
+}
+
+/// Elements of a list value, borrowed only when compared or displayed. Elements are
+/// compared and displayed through their own TypeMatchers, a list containing itself
+/// is displayed as `[...]`.
+pub struct Elements<'a>(pub &'a RefCell<Vec<Rc<dyn Value>>>);
+
+impl Elements<'_> {
+    fn address(&self) -> usize {
+        self.0.as_ptr() as usize
+    }
+}
+
+impl PartialEq for Elements<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        // Comparison that reaches the same lists again is decided by the other elements
+        visit_once((self.address(), other.address()), || {
+            let (elements, others) = (self.0.borrow(), other.0.borrow());
+            elements.len() == others.len() &&
+                elements.iter().zip(others.iter()).all(|(a, b)| a.type_matcher() == b.type_matcher())
+        }).unwrap_or(true)
+    }
+}
+
+impl Debug for Elements<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        visit_once((self.address(), 0), || {
+            f.debug_list().entries(self.0.borrow().iter().map(|value| value.type_matcher())).finish()
+        }).unwrap_or_else(|| write!(f, "[...]"))
+    }
+}
+
+impl Display for Elements<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        visit_once((self.address(), 0), || {
+            for (i, value) in self.0.borrow().iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", display_element(value))?;
+            }
+            Ok(())
+        }).unwrap_or_else(|| write!(f, "..."))
+    }
+}
+
+/// Key value pairs of a map value in insertion order, borrowed only when compared
+/// or displayed. A map containing itself is displayed as `{...}`.
+pub struct Entries<'a>(pub &'a RefCell<Vec<MapEntry>>);
+
+/// Key and value of a map entry
+pub type MapEntry = (Rc<dyn Value>, Rc<dyn Value>);
+
+impl Entries<'_> {
+    fn address(&self) -> usize {
+        self.0.as_ptr() as usize
+    }
+}
+
+impl PartialEq for Entries<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        visit_once((self.address(), other.address()), || {
+            let (entries, others) = (self.0.borrow(), other.0.borrow());
+            entries.len() == others.len() &&
+                entries.iter().zip(others.iter()).all(|((k1, v1), (k2, v2))|
+                    k1.type_matcher() == k2.type_matcher() && v1.type_matcher() == v2.type_matcher())
+        }).unwrap_or(true)
+    }
+}
+
+impl Debug for Entries<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        visit_once((self.address(), 0), || {
+            f.debug_map().entries(self.0.borrow().iter().map(|(k, v)| (k.type_matcher(), v.type_matcher()))).finish()
+        }).unwrap_or_else(|| write!(f, "{{...}}"))
+    }
+}
+
+impl Display for Entries<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        visit_once((self.address(), 0), || {
+            for (i, (key, value)) in self.0.borrow().iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}: {}", display_element(key), display_element(value))?;
+            }
+            Ok(())
+        }).unwrap_or_else(|| write!(f, "..."))
+    }
+}
+
+/// Named fields of a struct value in declaration order, borrowed only when compared
+/// or displayed. A struct containing itself is displayed as `Name { ... }`.
+pub struct Fields<'a>(pub &'a RefCell<Vec<StructField>>);
+
+/// Name and value of a struct field
+pub type StructField = (String, Rc<dyn Value>);
+
+impl Fields<'_> {
+    fn address(&self) -> usize {
+        self.0.as_ptr() as usize
+    }
+}
+
+impl PartialEq for Fields<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        visit_once((self.address(), other.address()), || {
+            let (fields, others) = (self.0.borrow(), other.0.borrow());
+            fields.len() == others.len() &&
+                fields.iter().zip(others.iter()).all(|((n1, v1), (n2, v2))|
+                    n1 == n2 && v1.type_matcher() == v2.type_matcher())
+        }).unwrap_or(true)
+    }
+}
+
+impl Debug for Fields<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        visit_once((self.address(), 0), || {
+            f.debug_map().entries(self.0.borrow().iter().map(|(n, v)| (n.clone(), v.type_matcher()))).finish()
+        }).unwrap_or_else(|| write!(f, "{{...}}"))
+    }
+}
+
+impl Display for Fields<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        visit_once((self.address(), 0), || {
+            for (i, (name, value)) in self.0.borrow().iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}: {}", name, display_element(value))?;
+            }
+            Ok(())
+        }).unwrap_or_else(|| write!(f, "..."))
+    }
+}
+
+/// Strings are quoted inside collections, so that ["a, b"] is readable
+fn display_element(value: &Rc<dyn Value>) -> String {
+    match value.type_matcher() {
+        TypeMatcher::String(v) => format!("{:?}", v),
+        matcher => format!("{}", matcher),
+    }
+}
+
+/// Identity of a value used as a map key.
+/// Contract: values with equal HashKeys must be equal with `apply_equals`, and vice versa.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum HashKey {
+    Integer(i32),
+    String(String),
+    Boolean(bool),
+}
+
+/// Signal for breaking out of loop or continuing loop, optionally to labeled loop
+#[derive(Debug, PartialEq)]
+pub enum LoopControl<'a> {
+    Break(Option<&'a String>),
+    Continue(Option<&'a String>),
+}
+
+impl Display for LoopControl<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoopControl::Break(None) => write!(f, "break"),
+            LoopControl::Break(Some(label)) => write!(f, "break {}", label),
+            LoopControl::Continue(None) => write!(f, "continue"),
+            LoopControl::Continue(Some(label)) => write!(f, "continue {}", label),
+        }
+    }
+}
+
+impl LoopControl<'_> {
+    pub fn label(&self) -> Option<&String> {
+        match self {
+            LoopControl::Break(label) => *label,
+            LoopControl::Continue(label) => *label,
+        }
+    }
+    /// Unlabeled break and continue always target the innermost loop
+    pub fn targets(&self, loop_label: Option<&String>) -> bool {
+        match self.label() {
+            None => true,
+            Some(label) => loop_label == Some(label),
+        }
+    }
+}
+
 // Hmm, programmatic equality should be in Value trait, this way TypeMatcher can
 // be used for test assertions (Void and such)
 // impl PartialEq for TypeMatcher<'_> {
@@ -113,18 +524,36 @@ impl Display for TypeMatcher<'_> {
 //     }
 // }
 
+/// Target and index expressions of `target[index]`
+pub type IndexAccess<'a> = (&'a Rc<dyn Expression>, &'a Rc<dyn Expression>);
+
+/// Target expression and member name of `target.name`
+pub type MemberAccess<'a> = (&'a Rc<dyn Expression>, &'a String);
+
 pub trait Expression {
     fn get_identifier(&self) -> Result<&String, EvaluationError> {
-        Err( EvaluationError { msg: "Token does not have identifier".to_string() } )
+        Err( EvaluationError::new("Token does not have identifier".to_string()) )
+    }
+    /// Used for index assignment `target[index] = value`
+    fn get_index(&self) -> Result<IndexAccess<'_>, EvaluationError> {
+        Err( EvaluationError::new("Expression is not index access".to_string()) )
+    }
+    /// Used for member assignment `target.name = value`
+    fn get_member_access(&self) -> Result<MemberAccess<'_>, EvaluationError> {
+        Err( EvaluationError::new("Expression is not member access".to_string()) )
     }
+    /// Doc comment (`///`) attached to this expression, for tooling
+    fn doc(&self) -> Option<&String> { None }
     fn is_return(&self) -> bool { false }
     fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError>;
     fn visualize(&self, level: usize);
 }
 
 pub trait Value {
-    fn type_matcher(&self) -> TypeMatcher;
+    fn type_matcher(&self) -> TypeMatcher<'_>;
     fn is_return_value(&self) -> bool { false }
+    fn unwrap_return_value(&self) -> Option<Rc<dyn Value>> { None }
+    fn loop_control(&self) -> Option<LoopControl<'_>> { None }
     fn is_truthy(&self) -> bool { true } // true, because most of the values are truthy!!
     fn apply_equals(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
         Err( EvaluationError::operator_not_applicable(
@@ -159,7 +588,106 @@ pub trait Value {
             self.type_matcher(),
             other.type_matcher()))
     }
-    fn call(&self, _scope: &mut Scope, _args: Vec<Rc<dyn Value>>) -> Result<Rc<dyn Value>, EvaluationError> {
+    fn apply_division(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
+        Err( EvaluationError::operator_not_applicable(
+            "/",
+            self.type_matcher(),
+            other.type_matcher()))
+    }
+    fn apply_modulo(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
+        Err( EvaluationError::operator_not_applicable(
+            "%",
+            self.type_matcher(),
+            other.type_matcher()))
+    }
+    /// Ordering of this value against other value, None if values are not comparable.
+    /// Comparison operators are built on this.
+    fn compare(&self, _other: &Rc<dyn Value>) -> Option<Ordering> {
+        None
+    }
+    fn apply_less_than(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
+        match self.compare(&other) {
+            Some(ordering) => Ok(BooleanValue::rc(ordering == Ordering::Less)),
+            None => Err( EvaluationError::operator_not_applicable(
+                "<",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn apply_greater_than(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
+        match self.compare(&other) {
+            Some(ordering) => Ok(BooleanValue::rc(ordering == Ordering::Greater)),
+            None => Err( EvaluationError::operator_not_applicable(
+                ">",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn apply_less_or_equals(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
+        match self.compare(&other) {
+            Some(ordering) => Ok(BooleanValue::rc(ordering != Ordering::Greater)),
+            None => Err( EvaluationError::operator_not_applicable(
+                "<=",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn apply_greater_or_equals(&self, other: Rc<dyn Value>) ->  Result<Rc<dyn Value>, EvaluationError> {
+        match self.compare(&other) {
+            Some(ordering) => Ok(BooleanValue::rc(ordering != Ordering::Less)),
+            None => Err( EvaluationError::operator_not_applicable(
+                ">=",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    /// Elements of this value for `for` loops
+    fn iterate(&self) -> Result<Box<dyn Iterator<Item = Rc<dyn Value>>>, EvaluationError> {
+        Err( EvaluationError::not_iterable(self.type_matcher()))
+    }
+    /// Key for using this value as map key, see HashKey for the contract
+    fn hash_key(&self) -> Result<HashKey, EvaluationError> {
+        Err( EvaluationError::not_hashable(self.type_matcher()))
+    }
+    /// Element access with `value[index]`
+    fn index(&self, _index: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Err( EvaluationError::not_indexable(self.type_matcher()))
+    }
+    /// Element assignment with `value[index] = element`
+    fn assign_index(&self, _index: Rc<dyn Value>, _element: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        Err( EvaluationError::not_indexable(self.type_matcher()))
+    }
+    /// Member access with `value.name`
+    fn get_member(&self, name: &str) -> Result<Rc<dyn Value>, EvaluationError> {
+        Err( EvaluationError::no_member(self.type_matcher(), name))
+    }
+    /// Member assignment with `value.name = member`
+    fn assign_member(&self, name: &str, _member: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        Err( EvaluationError::no_member(self.type_matcher(), name))
+    }
+    /// Struct construction with `Name { field: value }`, fields are in the order given
+    fn construct(&self, _fields: Vec<(String, Rc<dyn Value>)>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Err( EvaluationError::not_a_struct(self.type_matcher()))
+    }
+    /// Built-in method call with `value.name(args)`
+    fn call_method(&self, name: &str, _args: Vec<Rc<dyn Value>>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Err( EvaluationError::no_member(self.type_matcher(), name))
+    }
+    /// Method call with named arguments `value.name(1, b: 2)`, built-in methods take none
+    fn call_method_named(&self,
+                         name: &str,
+                         args: Vec<Rc<dyn Value>>,
+                         named: Vec<(String, Rc<dyn Value>)>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match named.first() {
+            Some((argument, _)) => Err( EvaluationError::named_argument_to_method(self.type_matcher(), name, argument)),
+            None => self.call_method(name, args),
+        }
+    }
+    /// Call with positional arguments followed by named arguments `f(1, b: 2)`
+    fn call(&self,
+            _scope: &mut Scope,
+            _args: Vec<Rc<dyn Value>>,
+            _named: Vec<(String, Rc<dyn Value>)>) -> Result<Rc<dyn Value>, EvaluationError> {
         Err( EvaluationError::not_callable(self.type_matcher()))
     }
 }
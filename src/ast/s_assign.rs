@@ -3,36 +3,83 @@ use std::rc::Rc;
 use crate::ast::{Expression, Value, EvaluationError};
 use crate::ast::v_void::Void;
 
+/// Operator of compound assignment `x += 1`, that assigns `x + 1` to `x`
+#[derive(Debug, Clone, Copy)]
+pub enum CompoundOperator {
+    Plus,
+    Minus,
+    Multiplication,
+    Division,
+}
+
+impl CompoundOperator {
+    pub fn apply(&self, current: Rc<dyn Value>, value: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match self {
+            CompoundOperator::Plus => current.apply_plus(value),
+            CompoundOperator::Minus => current.apply_minus(value),
+            CompoundOperator::Multiplication => current.apply_multiplication(value),
+            CompoundOperator::Division => current.apply_division(value),
+        }
+    }
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CompoundOperator::Plus => "+=",
+            CompoundOperator::Minus => "-=",
+            CompoundOperator::Multiplication => "*=",
+            CompoundOperator::Division => "/=",
+        }
+    }
+}
+
 pub struct AssignStatement {
     identifier: String,
+    operator: Option<CompoundOperator>,
     expression: Rc<dyn Expression>,
 }
 
 impl AssignStatement {
-    pub fn new(identifier: String, expression: Rc<dyn Expression>) -> AssignStatement {
+    /// Assignment with `=` when operator is None, otherwise compound assignment like `+=`
+    pub fn new(identifier: String,
+               operator: Option<CompoundOperator>,
+               expression: Rc<dyn Expression>) -> AssignStatement {
         AssignStatement {
             identifier,
+            operator,
             expression,
         }
     }
-    pub fn rc(identifier: String, expression: Rc<dyn Expression>) -> Rc<AssignStatement> {
-        Rc::new(AssignStatement::new(identifier, expression))
+    pub fn rc(identifier: String,
+              operator: Option<CompoundOperator>,
+              expression: Rc<dyn Expression>) -> Rc<AssignStatement> {
+        Rc::new(AssignStatement::new(identifier, operator, expression))
     }
 }
 
 impl Expression for AssignStatement {
     fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        if scope.is_const(&self.identifier) {
+            return Err( EvaluationError::cant_assign_constant(self.identifier.as_str()))
+        }
+        // Right hand side is evaluated first, it may change the current value
+        let value = self.expression.evaluate(scope)?;
         match scope.resolve(&self.identifier) {
-            Some(_) => {
-                let value = self.expression.evaluate(scope)?;
-                scope.store(self.identifier.clone(), value);
+            Some(current) => {
+                let value = match self.operator {
+                    Some(operator) => operator.apply(current, value)?,
+                    None => value,
+                };
+                // Binding is updated in the scope where it was declared
+                scope.assign(&self.identifier, value)?;
                 Ok(Rc::new(Void))
             },
-            None => Err( EvaluationError::cant_assing(&self.identifier.as_str()))
+            None => Err( EvaluationError::cant_assing(self.identifier.as_str()))
         }
     }
     fn visualize(&self, level: usize) {
-        println!("{} AssignStatement ({})", "-".repeat(level), self.identifier);
+        match self.operator {
+            Some(operator) => println!("{} AssignStatement ({} {})", "-".repeat(level), self.identifier, operator.symbol()),
+            None => println!("{} AssignStatement ({})", "-".repeat(level), self.identifier),
+        }
         self.expression.visualize(level + 1);
     }
 }
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError, TypeMatcher, LoopControl};
+
+pub struct BreakStatement {
+    label: Option<String>,
+}
+
+impl BreakStatement {
+    pub fn new(label: Option<String>) -> BreakStatement {
+        BreakStatement {
+            label,
+        }
+    }
+    pub fn rc(label: Option<String>) -> Rc<BreakStatement> {
+        Rc::new(BreakStatement::new(label))
+    }
+}
+
+impl Expression for BreakStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        scope.check_loop_control(LoopControl::Break(self.label.as_ref()))?;
+        Ok(Rc::new(BreakValue { label: self.label.clone() }))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} {}", "-".repeat(level), LoopControl::Break(self.label.as_ref()));
+    }
+}
+
+pub struct ContinueStatement {
+    label: Option<String>,
+}
+
+impl ContinueStatement {
+    pub fn new(label: Option<String>) -> ContinueStatement {
+        ContinueStatement {
+            label,
+        }
+    }
+    pub fn rc(label: Option<String>) -> Rc<ContinueStatement> {
+        Rc::new(ContinueStatement::new(label))
+    }
+}
+
+impl Expression for ContinueStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        scope.check_loop_control(LoopControl::Continue(self.label.as_ref()))?;
+        Ok(Rc::new(ContinueValue { label: self.label.clone() }))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} {}", "-".repeat(level), LoopControl::Continue(self.label.as_ref()));
+    }
+}
+
+pub struct BreakValue {
+    label: Option<String>,
+}
+
+impl Value for BreakValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Void
+    }
+    fn loop_control(&self) -> Option<LoopControl<'_>> {
+        Some(LoopControl::Break(self.label.as_ref()))
+    }
+}
+
+pub struct ContinueValue {
+    label: Option<String>,
+}
+
+impl Value for ContinueValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Void
+    }
+    fn loop_control(&self) -> Option<LoopControl<'_>> {
+        Some(LoopControl::Continue(self.label.as_ref()))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::v_void::Void;
+
+pub struct ConstStatement {
+    identifier: String,
+    expression: Rc<dyn Expression>,
+    doc: Option<String>,
+}
+
+impl ConstStatement {
+    pub fn new(identifier: String, expression: Rc<dyn Expression>, doc: Option<String>) -> ConstStatement {
+        ConstStatement {
+            identifier,
+            expression,
+            doc,
+        }
+    }
+    pub fn rc(identifier: String, expression: Rc<dyn Expression>, doc: Option<String>) -> Rc<ConstStatement> {
+        Rc::new(ConstStatement::new(identifier, expression, doc))
+    }
+}
+
+impl Expression for ConstStatement {
+    fn doc(&self) -> Option<&String> {
+        self.doc.as_ref()
+    }
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        if scope.is_local_const(&self.identifier) {
+            return Err(EvaluationError::cant_redeclare_constant(&self.identifier));
+        }
+        let value = self.expression.evaluate(scope)?;
+        scope.store_const(self.identifier.clone(), value);
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} ConstStatement ({})", "-".repeat(level), self.identifier);
+        if let Some(doc) = self.doc() {
+            println!("{} Doc({:?})", "-".repeat(level + 1), doc);
+        }
+        self.expression.visualize(level + 1);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError, LoopControl};
+use crate::ast::v_void::Void;
+
+pub struct ForStatement {
+    label: Option<String>,
+    identifier: String,
+    iterable: Rc<dyn Expression>,
+    body: Rc<dyn Expression>,
+}
+
+impl ForStatement {
+    pub fn new(label: Option<String>,
+               identifier: String,
+               iterable: Rc<dyn Expression>,
+               body: Rc<dyn Expression>) -> ForStatement {
+        ForStatement {
+            label,
+            identifier,
+            iterable,
+            body,
+        }
+    }
+    pub fn rc(label: Option<String>,
+              identifier: String,
+              iterable: Rc<dyn Expression>,
+              body: Rc<dyn Expression>) -> Rc<ForStatement> {
+        Rc::new(ForStatement::new(label, identifier, iterable, body))
+    }
+}
+
+impl Expression for ForStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let loop_scope = scope.in_loop(self.label.as_ref());
+        for element in self.iterable.evaluate(scope)?.iterate()? {
+            // Each iteration gets a fresh scope, so bindings do not leak between iterations
+            let mut iteration_scope = Scope::sub(&loop_scope);
+            iteration_scope.store(self.identifier.clone(), element);
+            let value = self.body.evaluate(&mut iteration_scope)?;
+            if value.is_return_value() {
+                return Ok(value)
+            }
+            match value.loop_control() {
+                Some(control @ LoopControl::Break(_)) if control.targets(self.label.as_ref()) => break,
+                Some(control @ LoopControl::Continue(_)) if control.targets(self.label.as_ref()) => continue,
+                // Targets some outer loop
+                Some(_) => return Ok(value),
+                None => (),
+            }
+        }
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        match &self.label {
+            Some(label) => println!("{} ForStatement ({})", "-".repeat(level), label),
+            None => println!("{} ForStatement", "-".repeat(level)),
+        }
+        println!("{} Identifier({})", "-".repeat(level + 1), self.identifier);
+        println!("{} Iterable", "-".repeat(level + 1));
+        self.iterable.visualize(level + 2);
+        println!("{} Body", "-".repeat(level + 1));
+        self.body.visualize(level + 2);
+    }
+}
\ No newline at end of file
@@ -2,46 +2,110 @@ use crate::ast::scope::Scope;
 use std::rc::Rc;
 use crate::ast::{Expression, Value, EvaluationError, TypeMatcher};
 use crate::ast::v_void::Void;
-use crate::ast::e_identifier::IdentifierExpression;
+use crate::ast::v_list::ListValue;
+
+/// Function parameter, default value is evaluated on call when argument is not given.
+/// Rest parameter `...name` is always last and collects remaining positional arguments into a list.
+pub struct Parameter {
+    name: String,
+    default: Option<Rc<dyn Expression>>,
+    rest: bool,
+}
+
+impl Parameter {
+    pub fn new(name: String, default: Option<Rc<dyn Expression>>) -> Parameter {
+        Parameter {
+            name,
+            default,
+            rest: false,
+        }
+    }
+    pub fn rest(name: String) -> Parameter {
+        Parameter {
+            name,
+            default: None,
+            rest: true,
+        }
+    }
+    pub fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+    pub fn is_rest(&self) -> bool {
+        self.rest
+    }
+}
+
+/// Visualizes the default values and rest of parameters
+fn visualize_defaults(parameters: &[Parameter], level: usize) {
+    for parameter in parameters {
+        if let Some(default) = &parameter.default {
+            println!("{} Default({})", "-".repeat(level), parameter.name);
+            default.visualize(level + 1);
+        }
+        if parameter.rest {
+            println!("{} Rest({})", "-".repeat(level), parameter.name);
+        }
+    }
+}
 
 pub struct FunStatement {
     identifier: String,
-    args: Rc<Vec<IdentifierExpression>>,
+    args: Rc<Vec<Parameter>>,
     expression: Rc<dyn Expression>,
+    doc: Option<String>,
 }
 
 impl FunStatement {
-    pub fn new(identifier: String, args: Vec<IdentifierExpression>, expression: Rc<dyn Expression>) -> FunStatement {
+    pub fn new(identifier: String,
+               args: Vec<Parameter>,
+               expression: Rc<dyn Expression>,
+               doc: Option<String>) -> FunStatement {
         FunStatement {
             identifier,
             args: Rc::new(args),
             expression,
+            doc,
         }
     }
-    pub fn rc(identifier: String, args: Vec<IdentifierExpression>, expression: Rc<dyn Expression>) -> Rc<FunStatement> {
-        Rc::new(FunStatement::new(identifier, args, expression))
+    pub fn rc(identifier: String,
+              args: Vec<Parameter>,
+              expression: Rc<dyn Expression>,
+              doc: Option<String>) -> Rc<FunStatement> {
+        Rc::new(FunStatement::new(identifier, args, expression, doc))
     }
 }
 
 impl Expression for FunStatement {
+    fn doc(&self) -> Option<&String> {
+        self.doc.as_ref()
+    }
     fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
-        scope.store(self.identifier.clone(),
-                    Rc::new(Function { expression: self.expression.clone(), args: self.args.clone() }));
+        // Function captures the scope it is defined in, including itself for recursion
+        let function = Function {
+            expression: self.expression.clone(),
+            args: self.args.clone(),
+            closure: scope.clone(),
+        };
+        scope.store(self.identifier.clone(), Rc::new(function));
         Ok(Rc::new(Void))
     }
     fn visualize(&self, level: usize) {
         println!("{} FunStatement {}({} args)", "-".repeat(level), self.identifier, self.args.len());
+        if let Some(doc) = self.doc() {
+            println!("{} Doc({:?})", "-".repeat(level + 1), doc);
+        }
+        visualize_defaults(&self.args, level + 1);
         self.expression.visualize(level + 1);
     }
 }
 
 pub struct AnonFunction {
     expression: Rc<dyn Expression>,
-    args: Rc<Vec<IdentifierExpression>>,
+    args: Rc<Vec<Parameter>>,
 }
 
 impl AnonFunction {
-    pub fn rc(args: Vec<IdentifierExpression>, expression: Rc<dyn Expression>) -> Rc<AnonFunction> {
+    pub fn rc(args: Vec<Parameter>, expression: Rc<dyn Expression>) -> Rc<AnonFunction> {
         Rc::new( AnonFunction {
             expression,
             args: Rc::new(args),
@@ -50,48 +114,93 @@ impl AnonFunction {
 }
 
 impl Expression for AnonFunction {
-    fn evaluate(&self, _scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
-        Ok(Rc::new(Function { expression: self.expression.clone(), args: self.args.clone() }))
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(Rc::new(Function {
+            expression: self.expression.clone(),
+            args: self.args.clone(),
+            closure: scope.clone(),
+        }))
     }
     fn visualize(&self, level: usize) {
         println!("{} AnonFunction _({} args)", "-".repeat(level), self.args.len());
+        visualize_defaults(&self.args, level + 1);
         self.expression.visualize(level + 1);
     }
 }
 
 pub struct Function {
     expression: Rc<dyn Expression>,
-    args: Rc<Vec<IdentifierExpression>>,
+    args: Rc<Vec<Parameter>>,
+    /// Scope where function was defined, body is evaluated in a sub scope of this.
+    /// A function stored in the frame it captured forms an `Rc` cycle, so that frame
+    /// is never freed. A `Weak` reference would break the cycle but also closures
+    /// returned from the function that defined them, so the leak is accepted for now.
+    closure: Scope,
 }
 
 impl Function {
-    pub fn rc(args: Vec<IdentifierExpression>, expression: Rc<dyn Expression>) -> Rc<Function> {
+    /// Function without captured bindings, for native functions
+    pub fn rc(args: Vec<Parameter>, expression: Rc<dyn Expression>) -> Rc<Function> {
         Rc::new( Function {
             expression,
             args: Rc::new(args),
+            closure: Scope::new(),
         })
     }
 }
 
 impl Value for Function {
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         TypeMatcher::Function
     }
-    fn call(&self, scope: &mut Scope, args: Vec<Rc<dyn Value>>) -> Result<Rc<dyn Value>, EvaluationError> {
-        if self.args.len() != args.len() {
-            return Err( EvaluationError::invalid_arity(self.args.len(), args.len()) )
+    fn call(&self,
+            _scope: &mut Scope,
+            mut args: Vec<Rc<dyn Value>>,
+            named: Vec<(String, Rc<dyn Value>)>) -> Result<Rc<dyn Value>, EvaluationError> {
+        let has_rest = self.args.last().is_some_and(|parameter| parameter.rest);
+        let positional = self.args.len() - has_rest as usize;
+        if !has_rest && positional < args.len() {
+            return Err( EvaluationError::invalid_arity(positional, args.len()) )
         }
-        let mut new_scope = Scope::sub(&scope);
-        for (i, a) in self.args.iter().enumerate() {
-            match args.get(i) {
-                Some(e) => {
-                    new_scope.store(a.name(), e.clone())
-                },
-                None => return Err(EvaluationError { msg: format!("No arg for binding {}", i) })
+        let rest = args.split_off(positional.min(args.len()));
+        let mut values: Vec<Option<Rc<dyn Value>>> = args.into_iter().map(Some).collect();
+        values.resize(positional, None);
+        if has_rest {
+            values.push(Some(ListValue::rc_value(rest)));
+        }
+        for (name, value) in named {
+            match self.args.iter().position(|parameter| parameter.name == name && !parameter.rest) {
+                Some(i) if values[i].is_some() => return Err(EvaluationError::argument_given_twice(&name)),
+                Some(i) => values[i] = Some(value),
+                None => return Err(EvaluationError::unknown_parameter(&name)),
             }
-
         }
-        // TODO: this probably must wrap ReturnValue out
-        self.expression.evaluate(&mut new_scope)
+        let missing: Vec<&str> = self.args.iter().zip(&values)
+            .filter(|(parameter, value)| value.is_none() && parameter.default.is_none())
+            .map(|(parameter, _)| parameter.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err( EvaluationError::missing_arguments(&missing) )
+        }
+        // Lexical scoping: caller's bindings are not visible in the function body
+        let mut new_scope = Scope::function_body(&self.closure);
+        for (parameter, value) in self.args.iter().zip(values) {
+            // Defaults see the closure and the parameters before them
+            let value = match (value, &parameter.default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => default.evaluate(&mut new_scope)?,
+                (None, None) => unreachable!("missing arguments are checked above"),
+            };
+            new_scope.store(parameter.name.clone(), value);
+        }
+        // ReturnValue must not leak out of the function, otherwise it would return the caller too
+        let value = self.expression.evaluate(&mut new_scope)?;
+        if let Some(control) = value.loop_control() {
+            return Err(EvaluationError::outside_of_loop(control))
+        }
+        match value.unwrap_return_value() {
+            Some(returned) => Ok(returned),
+            None => Ok(value),
+        }
     }
 }
\ No newline at end of file
@@ -20,16 +20,19 @@ impl GroupedStatement {
 
 impl Expression for GroupedStatement {
     fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        // Group evaluates to the value of the last expression
+        let mut last_value: Rc<dyn Value> = Rc::new(Void);
         for expr in &self.expressions {
             if expr.is_return() {
                 return expr.evaluate(scope)
             }
             let value = expr.evaluate(scope)?;
-            if value.is_return_value() {
+            if value.is_return_value() || value.loop_control().is_some() {
                 return Ok(value)
             }
+            last_value = value;
         }
-        Ok(Rc::new(Void))
+        Ok(last_value)
     }
     fn visualize(&self, level: usize) {
         println!("{} GroupedStatement", "-".repeat(level));
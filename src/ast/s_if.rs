@@ -6,17 +6,23 @@ use crate::ast::v_void::Void;
 pub struct IfStatement {
     condition: Rc<dyn Expression>,
     branch: Rc<dyn Expression>,
+    else_branch: Option<Rc<dyn Expression>>,
 }
 
 impl IfStatement {
-    pub fn new(condition: Rc<dyn Expression>, branch: Rc<dyn Expression>) -> IfStatement {
+    pub fn new(condition: Rc<dyn Expression>,
+               branch: Rc<dyn Expression>,
+               else_branch: Option<Rc<dyn Expression>>) -> IfStatement {
         IfStatement {
             condition,
-            branch
+            branch,
+            else_branch,
         }
     }
-    pub fn rc(condition: Rc<dyn Expression>, branch: Rc<dyn Expression>) -> Rc<IfStatement> {
-        Rc::new(IfStatement::new(condition, branch))
+    pub fn rc(condition: Rc<dyn Expression>,
+              branch: Rc<dyn Expression>,
+              else_branch: Option<Rc<dyn Expression>>) -> Rc<IfStatement> {
+        Rc::new(IfStatement::new(condition, branch, else_branch))
     }
 }
 
@@ -25,9 +31,68 @@ impl Expression for IfStatement {
         if self.condition.evaluate(scope)?.is_truthy() {
             return self.branch.evaluate(scope);
         }
-        Ok(Rc::new(Void))
+        match &self.else_branch {
+            // else if is just an IfStatement in else branch
+            Some(else_branch) => else_branch.evaluate(scope),
+            None => Ok(Rc::new(Void)),
+        }
     }
     fn visualize(&self, level: usize) {
         println!("{} IfStatement", "-".repeat(level));
+        println!("{} Condition", "-".repeat(level + 1));
+        self.condition.visualize(level + 2);
+        println!("{} Then", "-".repeat(level + 1));
+        self.branch.visualize(level + 2);
+        if let Some(else_branch) = &self.else_branch {
+            println!("{} Else", "-".repeat(level + 1));
+            else_branch.visualize(level + 2);
+        }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, evaluates_to_void};
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+    use crate::ast::v_boolean::BooleanExpression;
+
+    #[test]
+    fn test_if_without_else() {
+        evaluates_to(
+            IfStatement::new(
+                BooleanExpression::rc(true),
+                IntegerExpression::rc(1),
+                None).evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(1),
+        );
+        evaluates_to_void(
+            IfStatement::new(
+                BooleanExpression::rc(false),
+                IntegerExpression::rc(1),
+                None).evaluate(&mut Scope::new()),
+        );
+    }
+
+    #[test]
+    fn test_if_with_else() {
+        evaluates_to(
+            IfStatement::new(
+                BooleanExpression::rc(false),
+                IntegerExpression::rc(1),
+                Some(IntegerExpression::rc(2))).evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(2),
+        );
+        evaluates_to(
+            IfStatement::new(
+                BooleanExpression::rc(false),
+                IntegerExpression::rc(1),
+                Some(IfStatement::rc(
+                    BooleanExpression::rc(true),
+                    IntegerExpression::rc(2),
+                    Some(IntegerExpression::rc(3))))).evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(2),
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::v_void::Void;
+use crate::ast::v_module::load_module;
+
+/// `import "path.bs"` brings the module's bindings to scope,
+/// `import "path.bs" as m` binds the module itself to `m`
+pub struct ImportStatement {
+    path: String,
+    alias: Option<String>,
+}
+
+impl ImportStatement {
+    pub fn new(path: String, alias: Option<String>) -> ImportStatement {
+        ImportStatement {
+            path,
+            alias,
+        }
+    }
+    pub fn rc(path: String, alias: Option<String>) -> Rc<ImportStatement> {
+        Rc::new(ImportStatement::new(path, alias))
+    }
+}
+
+impl Expression for ImportStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let module = load_module(scope, &self.path)?;
+        match &self.alias {
+            Some(alias) => scope.store(alias.clone(), module),
+            None => scope.store_all_from(module.exports()),
+        }
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        match &self.alias {
+            Some(alias) => println!("{} ImportStatement ({:?} as {})", "-".repeat(level), self.path, alias),
+            None => println!("{} ImportStatement ({:?})", "-".repeat(level), self.path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::IntegerValue;
+    use std::path::{Path, PathBuf};
+    use std::ops::Deref;
+    use std::{env, fs, process};
+
+    /// Temporary directory of a test, removed when the test ends
+    struct ModuleDir(PathBuf);
+
+    impl Deref for ModuleDir {
+        type Target = Path;
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for ModuleDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Writes given files to a fresh temporary directory
+    fn module_dir(name: &str, files: &[(&str, &str)]) -> ModuleDir {
+        let dir = env::temp_dir().join(format!("bogus-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (file, source) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, source).unwrap();
+        }
+        ModuleDir(dir.canonicalize().unwrap())
+    }
+
+    fn import(dir: &Path, path: &str, alias: Option<&str>) -> Result<Scope, EvaluationError> {
+        let mut scope = Scope::for_file(&dir.join("main.bs"));
+        ImportStatement::new(path.to_string(), alias.map(|a| a.to_string())).evaluate(&mut scope)?;
+        Ok(scope)
+    }
+
+    #[test]
+    fn test_import_bindings() {
+        let dir = module_dir("import-bindings", &[
+            ("lib/math.bs", "import \"consts.bs\"; fun add(a, b) -> a + b; let two = add(1, one);"),
+            ("lib/consts.bs", "const one = 1;"),
+        ]);
+        let scope = import(&dir, "lib/math.bs", None).unwrap();
+        evaluates_to(scope.resolve_result(&"two".to_string()), IntegerValue::rc_value(2));
+        // Imported bindings are exported too, constness is kept
+        assert!(scope.is_const(&"one".to_string()));
+        // Standard library of the module is not exported
+        assert!(scope.resolve(&"println".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_import_as_module() {
+        let dir = module_dir("import-as", &[
+            ("math.bs", "fun add(a, b) -> a + b; let two = 2;"),
+        ]);
+        let scope = import(&dir, "math.bs", Some("m")).unwrap();
+        let module = scope.resolve_result(&"m".to_string()).unwrap();
+        assert_eq!(format!("{}", module.type_matcher()), format!("module {:?}", dir.join("math.bs").display().to_string()));
+        evaluates_to(module.get_member("two"), IntegerValue::rc_value(2));
+        evaluates_to(module.call_method("add", vec![IntegerValue::rc_value(1), IntegerValue::rc_value(2)]),
+                     IntegerValue::rc_value(3));
+        errors_to(module.get_member("println"), "Module has no member `println`");
+        assert_eq!(module.assign_member("two", IntegerValue::rc_value(3)).unwrap_err().msg,
+                   "Can't assign to member `two`, modules are read-only");
+    }
+
+    #[test]
+    fn test_module_is_evaluated_once() {
+        let dir = module_dir("import-once", &[
+            ("state.bs", "let items = [];"),
+            ("a.bs", "import \"state.bs\"; items.push(1);"),
+            ("b.bs", "import \"state.bs\"; items.push(2);"),
+        ]);
+        let mut scope = Scope::for_file(&dir.join("main.bs"));
+        for path in ["a.bs", "b.bs", "state.bs"] {
+            ImportStatement::new(path.to_string(), None).evaluate(&mut scope).unwrap();
+        }
+        assert_eq!(format!("{}", scope.resolve_result(&"items".to_string()).unwrap().type_matcher()), "[1, 2]");
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let dir = module_dir("import-errors", &[
+            ("a.bs", "import \"b.bs\";"),
+            ("b.bs", "import \"a.bs\";"),
+            ("main.bs", "import \"main.bs\";"),
+            ("broken.bs", "let = 1;"),
+            ("failing.bs", "let x = 1;\nlet a = b;"),
+            ("throwing.bs", "throw 1;"),
+            ("importing.bs", "import \"failing.bs\";"),
+        ]);
+        let error = |path: &str| match import(&dir, path, None) {
+            Ok(_) => panic!("Expected import of {} to fail", path),
+            Err(e) => e.msg,
+        };
+        let a = dir.join("a.bs").display().to_string();
+        let b = dir.join("b.bs").display().to_string();
+        let main = dir.join("main.bs").display().to_string();
+        assert_eq!(error("a.bs"), format!("Can't import \"a.bs\": Can't import \"b.bs\": Import cycle: {} -> {} -> {}", a, b, a));
+        assert_eq!(error("main.bs"), format!("Import cycle: {} -> {}", main, main));
+        assert!(error("missing.bs").starts_with("Can't import \"missing.bs\": "));
+        assert!(error("broken.bs").starts_with("Can't import \"broken.bs\": "));
+        assert_eq!(error("failing.bs"), "Can't import \"failing.bs\": Can't resolve variable `b`");
+        assert_eq!(error("importing.bs"), "Can't import \"importing.bs\": Can't import \"failing.bs\": Can't resolve variable `b`");
+        // Location inside the module is kept once
+        match import(&dir, "importing.bs", None) {
+            Ok(_) => panic!("Expected import of importing.bs to fail"),
+            Err(e) => assert_eq!(e.to_string(), "Evaluation Error: Can't import \"importing.bs\": \
+                Can't import \"failing.bs\": Can't resolve variable `b` @ [2:0]"),
+        }
+        // Thrown values are not wrapped, so that they can be caught
+        assert_eq!(error("throwing.bs"), "Uncaught exception 1");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::v_void::Void;
+use crate::ast::s_assign::CompoundOperator;
+
+/// `xs[i] = value`, or `xs[i] += value` with compound operator
+pub struct IndexAssignStatement {
+    target: Rc<dyn Expression>,
+    index: Rc<dyn Expression>,
+    operator: Option<CompoundOperator>,
+    expression: Rc<dyn Expression>,
+}
+
+impl IndexAssignStatement {
+    /// Assignment with `=` when operator is None, otherwise compound assignment like `+=`
+    pub fn new(target: Rc<dyn Expression>,
+               index: Rc<dyn Expression>,
+               operator: Option<CompoundOperator>,
+               expression: Rc<dyn Expression>) -> IndexAssignStatement {
+        IndexAssignStatement {
+            target,
+            index,
+            operator,
+            expression,
+        }
+    }
+    pub fn rc(target: Rc<dyn Expression>,
+              index: Rc<dyn Expression>,
+              operator: Option<CompoundOperator>,
+              expression: Rc<dyn Expression>) -> Rc<IndexAssignStatement> {
+        Rc::new(IndexAssignStatement::new(target, index, operator, expression))
+    }
+}
+
+impl Expression for IndexAssignStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let target = self.target.evaluate(scope)?;
+        let index = self.index.evaluate(scope)?;
+        // Target and index are evaluated once, also for compound assignment.
+        // Right hand side is evaluated before reading the current element, it may change it.
+        let value = self.expression.evaluate(scope)?;
+        let value = match self.operator {
+            Some(operator) => operator.apply(target.index(index.clone())?, value)?,
+            None => value,
+        };
+        target.assign_index(index, value)?;
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        match self.operator {
+            Some(operator) => println!("{} IndexAssignStatement ({})", "-".repeat(level), operator.symbol()),
+            None => println!("{} IndexAssignStatement", "-".repeat(level)),
+        }
+        println!("{} Target", "-".repeat(level + 1));
+        self.target.visualize(level + 2);
+        println!("{} Index", "-".repeat(level + 1));
+        self.index.visualize(level + 2);
+        println!("{} Value", "-".repeat(level + 1));
+        self.expression.visualize(level + 2);
+    }
+}
\ No newline at end of file
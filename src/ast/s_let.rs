@@ -6,27 +6,38 @@ use crate::ast::v_void::Void;
 pub struct LetStatement {
     identifier: String,
     expression: Rc<dyn Expression>,
+    doc: Option<String>,
 }
 
 impl LetStatement {
-    pub fn new(identifier: String, expression: Rc<dyn Expression>) -> LetStatement {
+    pub fn new(identifier: String, expression: Rc<dyn Expression>, doc: Option<String>) -> LetStatement {
         LetStatement {
             identifier,
             expression,
+            doc,
         }
     }
-    pub fn rc(identifier: String, expression: Rc<dyn Expression>) -> Rc<LetStatement> {
-        Rc::new(LetStatement::new(identifier, expression))
+    pub fn rc(identifier: String, expression: Rc<dyn Expression>, doc: Option<String>) -> Rc<LetStatement> {
+        Rc::new(LetStatement::new(identifier, expression, doc))
     }
 }
 
 impl Expression for LetStatement {
+    fn doc(&self) -> Option<&String> {
+        self.doc.as_ref()
+    }
     fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        if scope.is_local_const(&self.identifier) {
+            return Err(EvaluationError::cant_redeclare_constant(&self.identifier));
+        }
         let value = self.expression.evaluate(scope)?;
         scope.store(self.identifier.clone(), value);
         Ok(Rc::new(Void))
     }
     fn visualize(&self, level: usize) {
         println!("{} LetStatement", "-".repeat(level));
+        if let Some(doc) = self.doc() {
+            println!("{} Doc({:?})", "-".repeat(level + 1), doc);
+        }
     }
 }
\ No newline at end of file
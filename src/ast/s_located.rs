@@ -0,0 +1,36 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::lexer::tokens::SourceRef;
+
+/// Statement of a program or a block with the location where it starts.
+/// Errors from the statement are reported at that location.
+pub struct LocatedStatement {
+    statement: Rc<dyn Expression>,
+    location: SourceRef,
+}
+
+impl LocatedStatement {
+    pub fn rc(statement: Rc<dyn Expression>, location: SourceRef) -> Rc<LocatedStatement> {
+        Rc::new(LocatedStatement {
+            statement,
+            location,
+        })
+    }
+}
+
+impl Expression for LocatedStatement {
+    fn doc(&self) -> Option<&String> {
+        self.statement.doc()
+    }
+    fn is_return(&self) -> bool {
+        self.statement.is_return()
+    }
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        self.statement.evaluate(scope).map_err(|error| error.at(&self.location))
+    }
+    // Location is not part of the tree
+    fn visualize(&self, level: usize) {
+        self.statement.visualize(level)
+    }
+}
\ No newline at end of file
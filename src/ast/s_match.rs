@@ -0,0 +1,129 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+
+/// Pattern on the left side of a match arm
+pub enum MatchPattern {
+    /// Literal value, compared with apply_equals
+    Literal(Rc<dyn Expression>),
+    /// Identifier matches anything and binds the value for the arm
+    Binding(String),
+    /// `_` matches anything
+    Wildcard,
+}
+
+impl MatchPattern {
+    /// Values of different types (`1` and `"a"`) simply don't match
+    fn matches(&self, value: &Rc<dyn Value>, scope: &mut Scope) -> Result<bool, EvaluationError> {
+        match self {
+            MatchPattern::Literal(literal) => {
+                let literal = literal.evaluate(scope)?;
+                Ok(value.apply_equals(literal).is_ok_and(|equals| equals.is_truthy()))
+            }
+            MatchPattern::Binding(_) | MatchPattern::Wildcard => Ok(true),
+        }
+    }
+    fn visualize(&self, level: usize) {
+        match self {
+            MatchPattern::Literal(literal) => literal.visualize(level),
+            MatchPattern::Binding(name) => println!("{} Binding({})", "-".repeat(level), name),
+            MatchPattern::Wildcard => println!("{} Wildcard", "-".repeat(level)),
+        }
+    }
+}
+
+pub struct MatchStatement {
+    value: Rc<dyn Expression>,
+    arms: Vec<(MatchPattern, Rc<dyn Expression>)>,
+}
+
+impl MatchStatement {
+    pub fn new(value: Rc<dyn Expression>, arms: Vec<(MatchPattern, Rc<dyn Expression>)>) -> MatchStatement {
+        MatchStatement {
+            value,
+            arms,
+        }
+    }
+    pub fn rc(value: Rc<dyn Expression>, arms: Vec<(MatchPattern, Rc<dyn Expression>)>) -> Rc<MatchStatement> {
+        Rc::new(MatchStatement::new(value, arms))
+    }
+}
+
+impl Expression for MatchStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let value = self.value.evaluate(scope)?;
+        for (pattern, body) in &self.arms {
+            if !pattern.matches(&value, scope)? {
+                continue;
+            }
+            // First matching arm wins, binding is visible only in its arm
+            return match pattern {
+                MatchPattern::Binding(name) => {
+                    let mut arm_scope = Scope::sub(scope);
+                    arm_scope.store(name.clone(), value);
+                    body.evaluate(&mut arm_scope)
+                }
+                _ => body.evaluate(scope),
+            };
+        }
+        Err(EvaluationError::no_match(&value))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} MatchStatement", "-".repeat(level));
+        println!("{} Value", "-".repeat(level + 1));
+        self.value.visualize(level + 2);
+        for (pattern, body) in &self.arms {
+            println!("{} Arm", "-".repeat(level + 1));
+            pattern.visualize(level + 2);
+            println!("{} Then", "-".repeat(level + 2));
+            body.visualize(level + 3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+    use crate::ast::v_string::StringExpression;
+    use crate::ast::e_identifier::IdentifierExpression;
+    use crate::ast::e_plus::PlusExpression;
+
+    fn arms() -> Vec<(MatchPattern, Rc<dyn Expression>)> {
+        vec![
+            (MatchPattern::Literal(StringExpression::rc("a".to_string())), IntegerExpression::rc(0)),
+            (MatchPattern::Literal(IntegerExpression::rc(1)), IntegerExpression::rc(10)),
+            (MatchPattern::Literal(IntegerExpression::rc(1)), IntegerExpression::rc(20)),
+            (MatchPattern::Binding("n".to_string()), PlusExpression::rc(
+                IdentifierExpression::rc("n".to_string()),
+                IntegerExpression::rc(100))),
+        ]
+    }
+
+    #[test]
+    fn test_match_first_matching_arm() {
+        evaluates_to(
+            MatchStatement::new(IntegerExpression::rc(1), arms()).evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(10),
+        );
+        let mut scope = Scope::new();
+        evaluates_to(
+            MatchStatement::new(IntegerExpression::rc(2), arms()).evaluate(&mut scope),
+            IntegerValue::rc_value(102),
+        );
+        // Binding does not leak out of the arm
+        assert!(scope.resolve(&"n".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_match_without_matching_arm() {
+        errors_to(
+            MatchStatement::new(IntegerExpression::rc(2), vec![
+                (MatchPattern::Literal(IntegerExpression::rc(1)), IntegerExpression::rc(10)),
+            ]).evaluate(&mut Scope::new()),
+            "No match arm for 2",
+        );
+    }
+}
\ No newline at end of file
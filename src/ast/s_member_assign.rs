@@ -0,0 +1,58 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+use crate::ast::v_void::Void;
+use crate::ast::s_assign::CompoundOperator;
+
+/// `p.x = value`, or `p.x += value` with compound operator
+pub struct MemberAssignStatement {
+    target: Rc<dyn Expression>,
+    name: String,
+    operator: Option<CompoundOperator>,
+    expression: Rc<dyn Expression>,
+}
+
+impl MemberAssignStatement {
+    /// Assignment with `=` when operator is None, otherwise compound assignment like `+=`
+    pub fn new(target: Rc<dyn Expression>,
+               name: String,
+               operator: Option<CompoundOperator>,
+               expression: Rc<dyn Expression>) -> MemberAssignStatement {
+        MemberAssignStatement {
+            target,
+            name,
+            operator,
+            expression,
+        }
+    }
+    pub fn rc(target: Rc<dyn Expression>,
+              name: String,
+              operator: Option<CompoundOperator>,
+              expression: Rc<dyn Expression>) -> Rc<MemberAssignStatement> {
+        Rc::new(MemberAssignStatement::new(target, name, operator, expression))
+    }
+}
+
+impl Expression for MemberAssignStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let target = self.target.evaluate(scope)?;
+        // Right hand side is evaluated before reading the current member, it may change it
+        let value = self.expression.evaluate(scope)?;
+        let value = match self.operator {
+            Some(operator) => operator.apply(target.get_member(&self.name)?, value)?,
+            None => value,
+        };
+        target.assign_member(&self.name, value)?;
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        match self.operator {
+            Some(operator) => println!("{} MemberAssignStatement ({} {})", "-".repeat(level), self.name, operator.symbol()),
+            None => println!("{} MemberAssignStatement ({})", "-".repeat(level), self.name),
+        }
+        println!("{} Target", "-".repeat(level + 1));
+        self.target.visualize(level + 2);
+        println!("{} Value", "-".repeat(level + 1));
+        self.expression.visualize(level + 2);
+    }
+}
\ No newline at end of file
@@ -35,10 +35,13 @@ pub struct ReturnValue {
 }
 
 impl Value for ReturnValue {
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         self.value.type_matcher()
     }
     fn is_return_value(&self) -> bool {
         true
     }
+    fn unwrap_return_value(&self) -> Option<Rc<dyn Value>> {
+        Some(self.value.clone())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,116 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError, TypeMatcher};
+use crate::ast::v_void::Void;
+use crate::ast::v_struct::StructValue;
+
+/// Struct declaration `struct Point { x, y }`, binds the struct type to its name
+pub struct StructStatement {
+    name: String,
+    fields: Rc<Vec<String>>,
+}
+
+impl StructStatement {
+    pub fn new(name: String, fields: Vec<String>) -> StructStatement {
+        StructStatement {
+            name,
+            fields: Rc::new(fields),
+        }
+    }
+    pub fn rc(name: String, fields: Vec<String>) -> Rc<StructStatement> {
+        Rc::new(StructStatement::new(name, fields))
+    }
+}
+
+impl Expression for StructStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let struct_type = StructType {
+            name: self.name.clone(),
+            fields: self.fields.clone(),
+        };
+        scope.store(self.name.clone(), Rc::new(struct_type));
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} StructStatement {}", "-".repeat(level), self.name);
+        for field in self.fields.iter() {
+            println!("{} Field({})", "-".repeat(level + 1), field);
+        }
+    }
+}
+
+pub struct StructType {
+    name: String,
+    fields: Rc<Vec<String>>,
+}
+
+impl Value for StructType {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::StructType(&self.name)
+    }
+    /// Every declared field must be given exactly once
+    fn construct(&self, mut fields: Vec<(String, Rc<dyn Value>)>) -> Result<Rc<dyn Value>, EvaluationError> {
+        for (i, (name, _)) in fields.iter().enumerate() {
+            if !self.fields.contains(name) {
+                return Err(EvaluationError::no_field(self.type_matcher(), name));
+            }
+            if fields[..i].iter().any(|(previous, _)| previous == name) {
+                return Err(EvaluationError::duplicate_field(self.type_matcher(), name));
+            }
+        }
+        let mut ordered = vec![];
+        for declared in self.fields.iter() {
+            match fields.iter().position(|(name, _)| name == declared) {
+                Some(position) => ordered.push(fields.swap_remove(position)),
+                None => return Err(EvaluationError::missing_field(self.type_matcher(), declared)),
+            }
+        }
+        Ok(StructValue::rc_value(self.name.clone(), ordered))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::errors_to;
+    use crate::ast::v_integer::IntegerValue;
+
+    fn point() -> StructType {
+        StructType {
+            name: "Point".to_string(),
+            fields: Rc::new(vec!["x".to_string(), "y".to_string()]),
+        }
+    }
+
+    #[test]
+    fn test_construct_orders_fields() {
+        let constructed = point().construct(vec![
+            ("y".to_string(), IntegerValue::rc_value(2)),
+            ("x".to_string(), IntegerValue::rc_value(1)),
+        ]);
+        match constructed {
+            Ok(value) => assert_eq!(format!("{}", value.type_matcher()), "Point { x: 1, y: 2 }"),
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_construct_errors() {
+        errors_to(
+            point().construct(vec![("x".to_string(), IntegerValue::rc_value(1))]),
+            "Missing field `y` for struct Point");
+        errors_to(
+            point().construct(vec![
+                ("x".to_string(), IntegerValue::rc_value(1)),
+                ("z".to_string(), IntegerValue::rc_value(1)),
+            ]),
+            "struct Point has no field `z`");
+        errors_to(
+            point().construct(vec![
+                ("x".to_string(), IntegerValue::rc_value(1)),
+                ("x".to_string(), IntegerValue::rc_value(1)),
+            ]),
+            "Field `x` given twice for struct Point");
+    }
+}
\ No newline at end of file
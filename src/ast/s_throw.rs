@@ -0,0 +1,28 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+
+pub struct ThrowStatement {
+    expression: Rc<dyn Expression>,
+}
+
+impl ThrowStatement {
+    pub fn new(expression: Rc<dyn Expression>) -> ThrowStatement {
+        ThrowStatement {
+            expression,
+        }
+    }
+    pub fn rc(expression: Rc<dyn Expression>) -> Rc<ThrowStatement> {
+        Rc::new(ThrowStatement::new(expression))
+    }
+}
+
+impl Expression for ThrowStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        Err(EvaluationError::thrown(self.expression.evaluate(scope)?))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} ThrowStatement", "-".repeat(level));
+        self.expression.visualize(level + 1);
+    }
+}
\ No newline at end of file
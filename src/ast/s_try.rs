@@ -0,0 +1,128 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError};
+
+/// `try { } catch e { } finally { }`, either catch or finally may be left out
+pub struct TryStatement {
+    body: Rc<dyn Expression>,
+    catch: Option<(String, Rc<dyn Expression>)>,
+    finally: Option<Rc<dyn Expression>>,
+}
+
+impl TryStatement {
+    pub fn new(body: Rc<dyn Expression>,
+               catch: Option<(String, Rc<dyn Expression>)>,
+               finally: Option<Rc<dyn Expression>>) -> TryStatement {
+        TryStatement {
+            body,
+            catch,
+            finally,
+        }
+    }
+    pub fn rc(body: Rc<dyn Expression>,
+              catch: Option<(String, Rc<dyn Expression>)>,
+              finally: Option<Rc<dyn Expression>>) -> Rc<TryStatement> {
+        Rc::new(TryStatement::new(body, catch, finally))
+    }
+}
+
+impl Expression for TryStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        // Try evaluates to the value of the body, or of the catch when body failed
+        let result = match (self.body.evaluate(scope), &self.catch) {
+            (Err(error), Some((identifier, catch))) => {
+                let mut catch_scope = Scope::sub(scope);
+                catch_scope.store(identifier.clone(), error.to_value());
+                catch.evaluate(&mut catch_scope)
+            }
+            (result, _) => result,
+        };
+        if let Some(finally) = &self.finally {
+            // Errors, returns and loop control from finally win over the result
+            let value = finally.evaluate(scope)?;
+            if value.is_return_value() || value.loop_control().is_some() {
+                return Ok(value);
+            }
+        }
+        result
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} TryStatement", "-".repeat(level));
+        self.body.visualize(level + 1);
+        if let Some((identifier, catch)) = &self.catch {
+            println!("{} Catch({})", "-".repeat(level + 1), identifier);
+            catch.visualize(level + 2);
+        }
+        if let Some(finally) = &self.finally {
+            println!("{} Finally", "-".repeat(level + 1));
+            finally.visualize(level + 2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::{IntegerExpression, IntegerValue};
+    use crate::ast::v_string::{StringExpression, StringValue};
+    use crate::ast::e_identifier::IdentifierExpression;
+    use crate::ast::s_throw::ThrowStatement;
+    use crate::ast::s_assign::AssignStatement;
+    use crate::ast::s_grouped::GroupedStatement;
+
+    fn throw(message: &str) -> Rc<dyn Expression> {
+        ThrowStatement::rc(StringExpression::rc(message.to_string()))
+    }
+
+    #[test]
+    fn test_catch_thrown_value() {
+        evaluates_to(
+            TryStatement::new(
+                throw("boom"),
+                Some(("e".to_string(), IdentifierExpression::rc("e".to_string()))),
+                None).evaluate(&mut Scope::new()),
+            StringValue::rc_value("boom".to_string()),
+        );
+        evaluates_to(
+            TryStatement::new(
+                IntegerExpression::rc(1),
+                Some(("e".to_string(), IntegerExpression::rc(2))),
+                None).evaluate(&mut Scope::new()),
+            IntegerValue::rc_value(1),
+        );
+    }
+
+    #[test]
+    fn test_finally_always_runs() {
+        let mut scope = Scope::new();
+        scope.store("a".to_string(), IntegerValue::rc_value(0));
+        let finally = AssignStatement::rc("a".to_string(), None, IntegerExpression::rc(1));
+        errors_to(
+            TryStatement::new(throw("boom"), None, Some(finally.clone())).evaluate(&mut scope),
+            "Uncaught exception \"boom\"",
+        );
+        evaluates_to(scope.resolve_result(&"a".to_string()), IntegerValue::rc_value(1));
+
+        // Error from catch is not caught again, finally still runs
+        scope.store("a".to_string(), IntegerValue::rc_value(0));
+        errors_to(
+            TryStatement::new(
+                throw("boom"),
+                Some(("e".to_string(), throw("again"))),
+                Some(finally)).evaluate(&mut scope),
+            "Uncaught exception \"again\"",
+        );
+        evaluates_to(scope.resolve_result(&"a".to_string()), IntegerValue::rc_value(1));
+
+        // Value of finally is not the value of try
+        evaluates_to(
+            TryStatement::new(
+                IntegerExpression::rc(1),
+                None,
+                Some(GroupedStatement::rc(vec![IntegerExpression::rc(2)]))).evaluate(&mut scope),
+            IntegerValue::rc_value(1),
+        );
+    }
+}
\ No newline at end of file
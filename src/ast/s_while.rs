@@ -0,0 +1,53 @@
+use crate::ast::scope::Scope;
+use std::rc::Rc;
+use crate::ast::{Expression, Value, EvaluationError, LoopControl};
+use crate::ast::v_void::Void;
+
+pub struct WhileStatement {
+    label: Option<String>,
+    condition: Rc<dyn Expression>,
+    body: Rc<dyn Expression>,
+}
+
+impl WhileStatement {
+    pub fn new(label: Option<String>, condition: Rc<dyn Expression>, body: Rc<dyn Expression>) -> WhileStatement {
+        WhileStatement {
+            label,
+            condition,
+            body,
+        }
+    }
+    pub fn rc(label: Option<String>, condition: Rc<dyn Expression>, body: Rc<dyn Expression>) -> Rc<WhileStatement> {
+        Rc::new(WhileStatement::new(label, condition, body))
+    }
+}
+
+impl Expression for WhileStatement {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let mut body_scope = scope.in_loop(self.label.as_ref());
+        while self.condition.evaluate(scope)?.is_truthy() {
+            let value = self.body.evaluate(&mut body_scope)?;
+            if value.is_return_value() {
+                return Ok(value)
+            }
+            match value.loop_control() {
+                Some(control @ LoopControl::Break(_)) if control.targets(self.label.as_ref()) => break,
+                Some(control @ LoopControl::Continue(_)) if control.targets(self.label.as_ref()) => continue,
+                // Targets some outer loop
+                Some(_) => return Ok(value),
+                None => (),
+            }
+        }
+        Ok(Rc::new(Void))
+    }
+    fn visualize(&self, level: usize) {
+        match &self.label {
+            Some(label) => println!("{} WhileStatement ({})", "-".repeat(level), label),
+            None => println!("{} WhileStatement", "-".repeat(level)),
+        }
+        println!("{} Condition", "-".repeat(level + 1));
+        self.condition.visualize(level + 2);
+        println!("{} Body", "-".repeat(level + 1));
+        self.body.visualize(level + 2);
+    }
+}
\ No newline at end of file
@@ -1,36 +1,146 @@
-use crate::ast::{Value, EvaluationError};
+use crate::ast::{Value, EvaluationError, LoopControl};
+use crate::ast::v_module::ModuleRegistry;
 use std::collections::HashMap;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
-pub struct Scope<'a> {
-    parent: Option<Box<&'a Scope<'a>>>,
-    registry: HashMap<String, Rc<dyn Value>>,
+/// Single named value in scope registry
+struct Binding {
+    value: Rc<dyn Value>,
+    constant: bool,
 }
 
-impl<'a> Scope<'a> {
-    pub fn new() -> Scope<'a> {
+/// Frame of the environment chain. Frames are shared by scopes and by the
+/// functions that captured them, so registry needs interior mutability.
+struct Frame {
+    parent: Option<Rc<Frame>>,
+    registry: RefCell<HashMap<String, Binding>>,
+}
+
+/// File the code is evaluated from, and the modules loaded by the whole program
+#[derive(Default)]
+pub struct ModuleContext {
+    /// None when evaluating REPL input, imports are then relative to working directory
+    pub path: Option<PathBuf>,
+    pub registry: Rc<RefCell<ModuleRegistry>>,
+}
+
+/// Handle to a shared, reference counted environment chain. Cloning a scope
+/// does not copy bindings, clones see and modify the same frames.
+#[derive(Clone)]
+pub struct Scope {
+    frame: Rc<Frame>,
+    module: Rc<ModuleContext>,
+    /// Labels of the loops enclosing the evaluated code, innermost last
+    loops: Vec<Option<String>>,
+}
+
+impl Scope {
+    pub fn new() -> Scope {
+        Scope::root(Rc::new(ModuleContext::default()))
+    }
+    /// Root scope for evaluating given file, its imports are relative to the file
+    pub fn for_file(path: &Path) -> Scope {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let registry = ModuleRegistry::loading(path.clone());
+        Scope::root(Rc::new(ModuleContext {
+            path: Some(path),
+            registry: Rc::new(RefCell::new(registry)),
+        }))
+    }
+    /// Root scope for evaluating imported module, shares module registry with this scope
+    pub fn for_module(&self, path: PathBuf) -> Scope {
+        Scope::root(Rc::new(ModuleContext {
+            path: Some(path),
+            registry: self.module.registry.clone(),
+        }))
+    }
+    fn root(module: Rc<ModuleContext>) -> Scope {
         Scope {
-            parent: None,
-            registry: HashMap::new(),
+            frame: Rc::new(Frame {
+                parent: None,
+                registry: RefCell::new(HashMap::new()),
+            }),
+            module,
+            loops: vec![],
         }
     }
-    pub fn sub(scope: &'a Scope) -> Scope<'a> {
+    pub fn sub(scope: &Scope) -> Scope {
         Scope {
-            parent: Some(Box::new(scope)),
-            registry: HashMap::new(),
+            frame: Rc::new(Frame {
+                parent: Some(scope.frame.clone()),
+                registry: RefCell::new(HashMap::new()),
+            }),
+            module: scope.module.clone(),
+            loops: scope.loops.clone(),
         }
     }
+    /// Sub scope for function body, loops around the definition or the call can't be
+    /// targeted by `break` or `continue` in the body
+    pub fn function_body(closure: &Scope) -> Scope {
+        let mut scope = Scope::sub(closure);
+        scope.loops.clear();
+        scope
+    }
+    /// Scope for loop body, sees and modifies the same bindings as this scope
+    pub fn in_loop(&self, label: Option<&String>) -> Scope {
+        let mut scope = self.clone();
+        scope.loops.push(label.cloned());
+        scope
+    }
+    /// Checks that `break` or `continue` targets one of the enclosing loops
+    pub fn check_loop_control(&self, control: LoopControl) -> Result<(), EvaluationError> {
+        if self.loops.is_empty() {
+            return Err(EvaluationError::outside_of_loop(control));
+        }
+        match control.label() {
+            Some(label) if !self.loops.iter().any(|loop_label| loop_label.as_ref() == Some(label)) =>
+                Err(EvaluationError::unknown_label(label)),
+            _ => Ok(()),
+        }
+    }
+    pub fn module(&self) -> &ModuleContext {
+        &self.module
+    }
     pub fn store(&mut self, name: String, value: Rc<dyn Value>) {
-        self.registry.insert(name, value);
+        self.frame.registry.borrow_mut().insert(name, Binding { value, constant: false });
     }
-    pub fn resolve(&self, name: &String) -> Option<Rc<dyn Value>> {
-        match self.registry.get(name) {
-            Some(v) => Some(v.clone()),
-            None => match &self.parent {
-                None => None,
-                Some(parent_scope) => parent_scope.resolve(name)
-            },
+    pub fn store_const(&mut self, name: String, value: Rc<dyn Value>) {
+        self.frame.registry.borrow_mut().insert(name, Binding { value, constant: true });
+    }
+    /// Replaces the value of the closest binding in the frame where it was declared
+    pub fn assign(&mut self, name: &String, value: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        let mut frame = Some(&self.frame);
+        while let Some(current) = frame {
+            if let Some(binding) = current.registry.borrow_mut().get_mut(name) {
+                if binding.constant {
+                    return Err(EvaluationError::cant_assign_constant(name));
+                }
+                binding.value = value;
+                return Ok(());
+            }
+            frame = current.parent.as_ref();
+        }
+        Err(EvaluationError::cant_assing(name))
+    }
+    /// Applies given function to the closest binding with given name
+    fn with_binding<T>(&self, name: &String, f: impl Fn(&Binding) -> T) -> Option<T> {
+        let mut frame = Some(&self.frame);
+        while let Some(current) = frame {
+            if let Some(binding) = current.registry.borrow().get(name) {
+                return Some(f(binding));
+            }
+            frame = current.parent.as_ref();
         }
+        None
+    }
+    pub fn resolve(&self, name: &String) -> Option<Rc<dyn Value>> {
+        self.with_binding(name, |binding| binding.value.clone())
+    }
+    /// Tells if the closest binding with given name is a constant
+    pub fn is_const(&self, name: &String) -> bool {
+        self.with_binding(name, |binding| binding.constant).unwrap_or(false)
     }
     pub fn resolve_result(&self, name: &String) -> Result<Rc<dyn Value>, EvaluationError> {
         match self.resolve(name) {
@@ -38,6 +148,21 @@ impl<'a> Scope<'a> {
             None => Err(EvaluationError::cant_resolve(name)),
         }
     }
+    /// Tells if a constant with given name is declared in this frame, it can't be redeclared there
+    pub fn is_local_const(&self, name: &str) -> bool {
+        self.frame.registry.borrow().get(name).is_some_and(|binding| binding.constant)
+    }
+    /// Resolves only bindings declared in this frame, not in parents
+    pub fn resolve_local(&self, name: &str) -> Option<Rc<dyn Value>> {
+        self.frame.registry.borrow().get(name).map(|binding| binding.value.clone())
+    }
+    /// Copies bindings declared in the frame of other scope to this frame, keeping constness
+    pub fn store_all_from(&mut self, other: &Scope) {
+        for (name, binding) in other.frame.registry.borrow().iter() {
+            let binding = Binding { value: binding.value.clone(), constant: binding.constant };
+            self.frame.registry.borrow_mut().insert(name.clone(), binding);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +202,73 @@ mod tests {
         resolves_to(&sub_scope, "foo", IntegerValue::rc_value(1));
     }
 
+    #[test]
+    fn const_bindings() {
+        let mut scope = Scope::new();
+        scope.store_const("foo".to_string(), IntegerValue::rc_value(1));
+        scope.store("bar".to_string(), IntegerValue::rc_value(2));
+
+        resolves_to(&scope, "foo", IntegerValue::rc_value(1));
+        assert!(scope.is_const(&"foo".to_string()));
+        assert!(!scope.is_const(&"bar".to_string()));
+        assert!(!scope.is_const(&"baz".to_string()));
+
+        let mut sub_scope = Scope::sub(&scope);
+        assert!(sub_scope.is_const(&"foo".to_string()));
+
+        assert!(scope.is_local_const("foo"));
+        assert!(!sub_scope.is_local_const("foo"));
+        assert!(!scope.is_local_const("bar"));
+
+        // Shadowing constant in sub scope makes it mutable there
+        sub_scope.store("foo".to_string(), IntegerValue::rc_value(3));
+        assert!(!sub_scope.is_const(&"foo".to_string()));
+    }
+
+    #[test]
+    fn assign_to_declaring_frame() {
+        let mut scope = Scope::new();
+        scope.store("foo".to_string(), IntegerValue::rc_value(1));
+        scope.store_const("bar".to_string(), IntegerValue::rc_value(1));
+
+        let mut sub_scope = Scope::sub(&scope);
+        assert!(sub_scope.assign(&"foo".to_string(), IntegerValue::rc_value(2)).is_ok());
+        resolves_to(&scope, "foo", IntegerValue::rc_value(2));
+
+        assert!(sub_scope.assign(&"bar".to_string(), IntegerValue::rc_value(2)).is_err());
+        assert!(sub_scope.assign(&"baz".to_string(), IntegerValue::rc_value(2)).is_err());
+        resolves_to(&scope, "bar", IntegerValue::rc_value(1));
+    }
+
+    #[test]
+    fn clones_share_bindings() {
+        let scope = Scope::new();
+        let mut sub_scope = Scope::sub(&scope);
+        let captured = sub_scope.clone();
+
+        // Bindings stored after cloning are visible through the clone
+        sub_scope.store("foo".to_string(), IntegerValue::rc_value(1));
+        resolves_to(&captured, "foo", IntegerValue::rc_value(1));
+        does_not_resolve(&scope, "foo");
+    }
+
+    #[test]
+    fn store_all_from_other_frame() {
+        let mut parent = Scope::new();
+        parent.store("parent".to_string(), IntegerValue::rc_value(1));
+        let mut other = Scope::sub(&parent);
+        other.store("foo".to_string(), IntegerValue::rc_value(2));
+        other.store_const("bar".to_string(), IntegerValue::rc_value(3));
+
+        let mut scope = Scope::new();
+        scope.store_all_from(&other);
+        resolves_to(&scope, "foo", IntegerValue::rc_value(2));
+        resolves_to(&scope, "bar", IntegerValue::rc_value(3));
+        assert!(scope.is_const(&"bar".to_string()));
+        does_not_resolve(&scope, "parent");
+        assert!(other.resolve_local("parent").is_none());
+    }
+
     fn resolves_to(scope: &Scope, key: &str, expected: Rc<dyn Value>) {
         let resolved = scope.resolve(&key.to_string());
         match resolved {
@@ -87,12 +279,10 @@ mod tests {
 
     fn does_not_resolve(scope: &Scope, key: &str) {
         let resolved = scope.resolve(&key.to_string());
-        match resolved {
-            Some(value) =>
-                panic!("Expected `{}` not to resolve but {:?} resolved",
-                       key,
-                       value.type_matcher()),
-            None => ()
+        if let Some(value) = resolved {
+            panic!("Expected `{}` not to resolve but {:?} resolved",
+                   key,
+                   value.type_matcher())
         }
     }
 }
\ No newline at end of file
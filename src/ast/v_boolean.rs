@@ -1,4 +1,4 @@
-use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher};
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher, HashKey};
 use std::rc::Rc;
 
 pub struct BooleanExpression {
@@ -32,10 +32,31 @@ impl BooleanValue {
 }
 
 impl Value for BooleanValue {
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         TypeMatcher::Boolean(&self.value)
     }
+    fn hash_key(&self) -> Result<HashKey, EvaluationError> {
+        Ok(HashKey::Boolean(self.value))
+    }
     fn is_truthy(&self) -> bool {
-        self.value == true
+        self.value
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Boolean(other_value) => Ok(BooleanValue::rc(self.value == *other_value)),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Boolean(other_value) => Ok(BooleanValue::rc(self.value != *other_value)),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
     }
 }
@@ -0,0 +1,195 @@
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher};
+use std::rc::Rc;
+use std::cmp::Ordering;
+use crate::ast::v_boolean::BooleanValue;
+
+pub struct FloatExpression {
+    value: f64,
+}
+
+impl FloatExpression {
+    pub fn new(value: f64) -> FloatExpression {
+        FloatExpression {
+            value,
+        }
+    }
+    pub fn rc(value: f64) -> Rc<FloatExpression> {
+        Rc::new(FloatExpression::new(value))
+    }
+}
+
+impl Expression for FloatExpression {
+    fn evaluate(&self, _: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(FloatValue::rc_from(self))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} Float({:?})", "-".repeat(level), self.value);
+    }
+}
+
+pub struct FloatValue {
+    value: f64,
+}
+
+impl FloatValue {
+    pub fn rc_from(expr: &FloatExpression) -> Rc<dyn Value> {
+        Rc::new(FloatValue { value: expr.value })
+    }
+    pub fn rc_value(value: f64) -> Rc<dyn Value> {
+        Rc::new(FloatValue { value })
+    }
+}
+
+/// Integers are promoted to floats when they meet floats
+fn promote(other: &TypeMatcher) -> Option<f64> {
+    match other {
+        TypeMatcher::Integer(value) => Some(**value as f64),
+        TypeMatcher::Float(value) => Some(**value),
+        _ => None,
+    }
+}
+
+impl Value for FloatValue {
+
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Float(&self.value)
+    }
+
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(other_value) => Ok(BooleanValue::rc(self.value == other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(other_value) => Ok(BooleanValue::rc(self.value != other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn compare(&self, other: &Rc<dyn Value>) -> Option<Ordering> {
+        match promote(&other.type_matcher()) {
+            Some(other_value) => self.value.partial_cmp(&other_value),
+            None => None,
+        }
+    }
+
+    fn apply_prefix_minus(&self) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(FloatValue::rc_value(-self.value))
+    }
+
+    fn apply_plus(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(other_value) => Ok(FloatValue::rc_value(self.value + other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "+",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_minus(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(other_value) => Ok(FloatValue::rc_value(self.value - other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "-",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_multiplication(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(other_value) => Ok(FloatValue::rc_value(self.value * other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "*",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_division(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(0.0) => Err(EvaluationError::division_by_zero()),
+            Some(other_value) => Ok(FloatValue::rc_value(self.value / other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "/",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_modulo(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match promote(&other.type_matcher()) {
+            Some(0.0) => Err(EvaluationError::division_by_zero()),
+            Some(other_value) => Ok(FloatValue::rc_value(self.value % other_value)),
+            None => Err(EvaluationError::operator_not_applicable(
+                "%",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::IntegerValue;
+    use crate::ast::v_string::StringValue;
+
+    #[test]
+    fn test_float_evaluate() {
+        evaluates_to(FloatExpression::new(1.5).evaluate(&mut Scope::new()),
+                     FloatValue::rc_value(1.5));
+    }
+
+    #[test]
+    fn test_float_operators() {
+        evaluates_to(FloatValue::rc_value(1.5).apply_plus(FloatValue::rc_value(1.5)),
+                     FloatValue::rc_value(3.0));
+        evaluates_to(FloatValue::rc_value(1.5).apply_plus(IntegerValue::rc_value(1)),
+                     FloatValue::rc_value(2.5));
+        evaluates_to(FloatValue::rc_value(1.5).apply_minus(IntegerValue::rc_value(1)),
+                     FloatValue::rc_value(0.5));
+        evaluates_to(FloatValue::rc_value(1.5).apply_multiplication(IntegerValue::rc_value(2)),
+                     FloatValue::rc_value(3.0));
+        evaluates_to(FloatValue::rc_value(1.0).apply_division(IntegerValue::rc_value(4)),
+                     FloatValue::rc_value(0.25));
+        evaluates_to(FloatValue::rc_value(5.5).apply_modulo(IntegerValue::rc_value(2)),
+                     FloatValue::rc_value(1.5));
+        evaluates_to(FloatValue::rc_value(1.5).apply_prefix_minus(),
+                     FloatValue::rc_value(-1.5));
+        evaluates_to(FloatValue::rc_value(2.0).apply_equals(IntegerValue::rc_value(2)),
+                     BooleanValue::rc(true));
+        evaluates_to(FloatValue::rc_value(2.0).apply_not_equals(FloatValue::rc_value(2.5)),
+                     BooleanValue::rc(true));
+        evaluates_to(FloatValue::rc_value(1.5).apply_greater_than(IntegerValue::rc_value(1)),
+                     BooleanValue::rc(true));
+        evaluates_to(FloatValue::rc_value(1.5).apply_less_or_equals(FloatValue::rc_value(1.5)),
+                     BooleanValue::rc(true));
+        errors_to(FloatValue::rc_value(1.0).apply_division(IntegerValue::rc_value(0)),
+                  "Division by zero");
+        errors_to(FloatValue::rc_value(1.0).apply_plus(StringValue::rc_value("a".to_string())),
+                  "Can't apply Float + String");
+    }
+
+    #[test]
+    fn test_float_display_round_trips() {
+        for value in [0.0, 1.0, -1.5, 0.1, 1.0 / 3.0, 123456.789] {
+            let displayed = format!("{}", FloatValue::rc_value(value).type_matcher());
+            assert_eq!(displayed.parse::<f64>().unwrap(), value);
+        }
+        // Whole floats are not displayed as integers
+        assert_eq!(format!("{}", FloatValue::rc_value(1.0).type_matcher()), "1.0");
+    }
+}
@@ -1,6 +1,8 @@
-use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher};
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher, HashKey};
 use std::rc::Rc;
+use std::cmp::Ordering;
 use crate::ast::v_boolean::BooleanValue;
+use crate::ast::v_float::FloatValue;
 
 pub struct IntegerExpression {
     value: i32,
@@ -13,9 +15,7 @@ impl IntegerExpression {
         }
     }
     pub fn rc(value: i32) -> Rc<IntegerExpression> {
-        Rc::new(IntegerExpression {
-            value,
-        })
+        Rc::new(IntegerExpression::new(value))
     }
 }
 
@@ -43,14 +43,20 @@ impl IntegerValue {
 
 impl Value for IntegerValue {
 
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         TypeMatcher::Integer(&self.value)
     }
 
+    fn hash_key(&self) -> Result<HashKey, EvaluationError> {
+        Ok(HashKey::Integer(self.value))
+    }
+
     fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
         match other.type_matcher() {
             TypeMatcher::Integer(other_value) =>
                 Ok(BooleanValue::rc(&self.value == other_value)),
+            TypeMatcher::Float(other_value) =>
+                Ok(BooleanValue::rc(self.value as f64 == *other_value)),
 
             _ => Err(EvaluationError::operator_not_applicable(
                 "eq/neq",
@@ -64,6 +70,8 @@ impl Value for IntegerValue {
         match other.type_matcher() {
             TypeMatcher::Integer(other_value) =>
                 Ok(BooleanValue::rc(&self.value != other_value)),
+            TypeMatcher::Float(other_value) =>
+                Ok(BooleanValue::rc(self.value as f64 != *other_value)),
 
             _ => Err(EvaluationError::operator_not_applicable(
                 "eq/neq",
@@ -72,14 +80,28 @@ impl Value for IntegerValue {
         }
     }
 
+    fn compare(&self, other: &Rc<dyn Value>) -> Option<Ordering> {
+        match other.type_matcher() {
+            TypeMatcher::Integer(other_value) => Some(self.value.cmp(other_value)),
+            TypeMatcher::Float(other_value) => (self.value as f64).partial_cmp(other_value),
+            _ => None,
+        }
+    }
+
     fn apply_prefix_minus(&self) -> Result<Rc<dyn Value>, EvaluationError> {
-        Ok(IntegerValue::rc_value(-self.value))
+        // -i32::MIN does not fit to i32
+        self.value.checked_neg()
+            .map(IntegerValue::rc_value)
+            .ok_or_else(EvaluationError::integer_overflow)
     }
 
     fn apply_plus(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
         match other.type_matcher() {
-            TypeMatcher::Integer(other_value) =>
-                Ok(IntegerValue::rc_value(self.value + other_value)),
+            TypeMatcher::Integer(other_value) => self.value.checked_add(*other_value)
+                .map(IntegerValue::rc_value)
+                .ok_or_else(EvaluationError::integer_overflow),
+            TypeMatcher::Float(other_value) =>
+                Ok(FloatValue::rc_value(self.value as f64 + other_value)),
 
             _ => Err(EvaluationError::operator_not_applicable(
                 "+",
@@ -90,11 +112,14 @@ impl Value for IntegerValue {
 
     fn apply_minus(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
         match other.type_matcher() {
-            TypeMatcher::Integer(other_value) =>
-                Ok(IntegerValue::rc_value(self.value - other_value)),
+            TypeMatcher::Integer(other_value) => self.value.checked_sub(*other_value)
+                .map(IntegerValue::rc_value)
+                .ok_or_else(EvaluationError::integer_overflow),
+            TypeMatcher::Float(other_value) =>
+                Ok(FloatValue::rc_value(self.value as f64 - other_value)),
 
             _ => Err(EvaluationError::operator_not_applicable(
-                "+",
+                "-",
                 self.type_matcher(),
                 other.type_matcher()))
         }
@@ -102,11 +127,49 @@ impl Value for IntegerValue {
 
     fn apply_multiplication(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
         match other.type_matcher() {
-            TypeMatcher::Integer(other_value) =>
-                Ok(IntegerValue::rc_value(self.value * other_value)),
+            TypeMatcher::Integer(other_value) => self.value.checked_mul(*other_value)
+                .map(IntegerValue::rc_value)
+                .ok_or_else(EvaluationError::integer_overflow),
+            TypeMatcher::Float(other_value) =>
+                Ok(FloatValue::rc_value(self.value as f64 * other_value)),
 
             _ => Err(EvaluationError::operator_not_applicable(
-                "+",
+                "*",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_division(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Integer(&0) => Err(EvaluationError::division_by_zero()),
+            // i32::MIN / -1 does not fit to i32
+            TypeMatcher::Integer(other_value) => self.value.checked_div(*other_value)
+                .map(IntegerValue::rc_value)
+                .ok_or_else(EvaluationError::integer_overflow),
+            TypeMatcher::Float(&0.0) => Err(EvaluationError::division_by_zero()),
+            TypeMatcher::Float(other_value) =>
+                Ok(FloatValue::rc_value(self.value as f64 / other_value)),
+
+            _ => Err(EvaluationError::operator_not_applicable(
+                "/",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+
+    fn apply_modulo(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Integer(&0) => Err(EvaluationError::division_by_zero()),
+            TypeMatcher::Integer(other_value) => self.value.checked_rem(*other_value)
+                .map(IntegerValue::rc_value)
+                .ok_or_else(EvaluationError::integer_overflow),
+            TypeMatcher::Float(&0.0) => Err(EvaluationError::division_by_zero()),
+            TypeMatcher::Float(other_value) =>
+                Ok(FloatValue::rc_value(self.value as f64 % other_value)),
+
+            _ => Err(EvaluationError::operator_not_applicable(
+                "%",
                 self.type_matcher(),
                 other.type_matcher()))
         }
@@ -117,7 +180,7 @@ impl Value for IntegerValue {
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
-    use crate::ast::tests::evaluates_to;
+    use crate::ast::tests::{evaluates_to, errors_to};
 
     #[test]
     fn test_integer_equals() {
@@ -131,6 +194,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_integer_division_and_modulo() {
+        evaluates_to(IntegerValue::rc_value(10).apply_division(IntegerValue::rc_value(3)),
+                     IntegerValue::rc_value(3));
+        evaluates_to(IntegerValue::rc_value(10).apply_modulo(IntegerValue::rc_value(3)),
+                     IntegerValue::rc_value(1));
+        errors_to(IntegerValue::rc_value(10).apply_division(IntegerValue::rc_value(0)),
+                  "Division by zero");
+        errors_to(IntegerValue::rc_value(10).apply_modulo(IntegerValue::rc_value(0)),
+                  "Division by zero");
+        errors_to(IntegerValue::rc_value(i32::MIN).apply_division(IntegerValue::rc_value(-1)),
+                  "Integer overflow");
+        errors_to(IntegerValue::rc_value(i32::MIN).apply_modulo(IntegerValue::rc_value(-1)),
+                  "Integer overflow");
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        evaluates_to(IntegerValue::rc_value(i32::MAX - 1).apply_plus(IntegerValue::rc_value(1)),
+                     IntegerValue::rc_value(i32::MAX));
+        errors_to(IntegerValue::rc_value(i32::MAX).apply_plus(IntegerValue::rc_value(1)),
+                  "Integer overflow");
+        errors_to(IntegerValue::rc_value(i32::MIN).apply_minus(IntegerValue::rc_value(1)),
+                  "Integer overflow");
+        errors_to(IntegerValue::rc_value(i32::MAX).apply_multiplication(IntegerValue::rc_value(2)),
+                  "Integer overflow");
+        errors_to(IntegerValue::rc_value(i32::MIN).apply_prefix_minus(), "Integer overflow");
+        evaluates_to(IntegerValue::rc_value(i32::MAX).apply_prefix_minus(), IntegerValue::rc_value(-i32::MAX));
+    }
+
+    #[test]
+    fn test_integer_float_promotion() {
+        evaluates_to(IntegerValue::rc_value(1).apply_plus(FloatValue::rc_value(0.5)),
+                     FloatValue::rc_value(1.5));
+        evaluates_to(IntegerValue::rc_value(1).apply_minus(FloatValue::rc_value(0.5)),
+                     FloatValue::rc_value(0.5));
+        evaluates_to(IntegerValue::rc_value(3).apply_multiplication(FloatValue::rc_value(0.5)),
+                     FloatValue::rc_value(1.5));
+        evaluates_to(IntegerValue::rc_value(3).apply_division(FloatValue::rc_value(2.0)),
+                     FloatValue::rc_value(1.5));
+        evaluates_to(IntegerValue::rc_value(3).apply_modulo(FloatValue::rc_value(2.0)),
+                     FloatValue::rc_value(1.0));
+        evaluates_to(IntegerValue::rc_value(1).apply_equals(FloatValue::rc_value(1.0)),
+                     BooleanValue::rc(true));
+        errors_to(IntegerValue::rc_value(1).apply_division(FloatValue::rc_value(0.0)),
+                  "Division by zero");
+    }
+
+    #[test]
+    fn test_integer_comparison() {
+        evaluates_to(IntegerValue::rc_value(1).apply_less_than(IntegerValue::rc_value(2)),
+                     BooleanValue::rc(true));
+        evaluates_to(IntegerValue::rc_value(2).apply_less_than(IntegerValue::rc_value(2)),
+                     BooleanValue::rc(false));
+        evaluates_to(IntegerValue::rc_value(2).apply_less_or_equals(IntegerValue::rc_value(2)),
+                     BooleanValue::rc(true));
+        evaluates_to(IntegerValue::rc_value(3).apply_greater_than(IntegerValue::rc_value(2)),
+                     BooleanValue::rc(true));
+        evaluates_to(IntegerValue::rc_value(1).apply_greater_or_equals(IntegerValue::rc_value(2)),
+                     BooleanValue::rc(false));
+        evaluates_to(IntegerValue::rc_value(1).apply_less_than(FloatValue::rc_value(1.5)),
+                     BooleanValue::rc(true));
+        errors_to(IntegerValue::rc_value(1).apply_less_than(BooleanValue::rc(true)),
+                  "Can't apply Integer < Boolean");
+    }
+
     #[test]
     fn test_integer_evaluate() {
         let integer_expr = IntegerExpression::new(1);
@@ -0,0 +1,192 @@
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher, Elements, check_arity, visit_once};
+use crate::ast::v_integer::IntegerValue;
+use crate::ast::v_boolean::BooleanValue;
+use crate::ast::v_void::Void;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+pub struct ListExpression {
+    elements: Vec<Rc<dyn Expression>>,
+}
+
+impl ListExpression {
+    pub fn new(elements: Vec<Rc<dyn Expression>>) -> ListExpression {
+        ListExpression {
+            elements,
+        }
+    }
+    pub fn rc(elements: Vec<Rc<dyn Expression>>) -> Rc<ListExpression> {
+        Rc::new(ListExpression::new(elements))
+    }
+}
+
+impl Expression for ListExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let mut values: Vec<Rc<dyn Value>> = vec![];
+        for element in &self.elements {
+            values.push(element.evaluate(scope)?);
+        }
+        Ok(ListValue::rc_value(values))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} List", "-".repeat(level));
+        for element in &self.elements {
+            element.visualize(level + 1);
+        }
+    }
+}
+
+/// Lists are shared by reference, `xs[0] = 1` is visible through every binding of the list
+pub struct ListValue {
+    values: RefCell<Vec<Rc<dyn Value>>>,
+}
+
+impl ListValue {
+    pub fn rc_value(values: Vec<Rc<dyn Value>>) -> Rc<dyn Value> {
+        Rc::new(ListValue { values: RefCell::new(values) })
+    }
+
+    fn position(&self, index: &Rc<dyn Value>) -> Result<usize, EvaluationError> {
+        let length = self.values.borrow().len();
+        match index.type_matcher() {
+            TypeMatcher::Integer(i) if *i >= 0 && (*i as usize) < length => Ok(*i as usize),
+            TypeMatcher::Integer(i) => Err(EvaluationError::index_out_of_range(*i, length)),
+            other => Err(EvaluationError::invalid_index(self.type_matcher(), other)),
+        }
+    }
+
+    /// Lists are equal when they have the same length and their elements are equal in order
+    fn equals(&self, other: &Rc<dyn Value>) -> Result<bool, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::List(Elements(other_values)) => {
+                // List containing itself is equal to itself as far as the other elements are
+                let addresses = (self.values.as_ptr() as usize, other_values.as_ptr() as usize);
+                visit_once(addresses, || {
+                    let (values, others) = (self.values.borrow(), other_values.borrow());
+                    if values.len() != others.len() {
+                        return Ok(false);
+                    }
+                    for (mine, theirs) in values.iter().zip(others.iter()) {
+                        if !mine.apply_equals(theirs.clone())?.is_truthy() {
+                            return Ok(false);
+                        }
+                    }
+                    Ok(true)
+                }).unwrap_or(Ok(true))
+            }
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+}
+
+impl Value for ListValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::List(Elements(&self.values))
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(self.equals(&other)?))
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(!self.equals(&other)?))
+    }
+    fn iterate(&self) -> Result<Box<dyn Iterator<Item = Rc<dyn Value>>>, EvaluationError> {
+        // Iterates over a snapshot, so the loop body may modify the list
+        Ok(Box::new(self.values.borrow().clone().into_iter()))
+    }
+    fn index(&self, index: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        let position = self.position(&index)?;
+        Ok(self.values.borrow()[position].clone())
+    }
+    fn assign_index(&self, index: Rc<dyn Value>, element: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        let position = self.position(&index)?;
+        self.values.borrow_mut()[position] = element;
+        Ok(())
+    }
+    fn call_method(&self, name: &str, args: Vec<Rc<dyn Value>>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match name {
+            "len" => {
+                check_arity(0, &args)?;
+                Ok(IntegerValue::rc_value(self.values.borrow().len() as i32))
+            }
+            "push" => {
+                check_arity(1, &args)?;
+                self.values.borrow_mut().extend(args);
+                Ok(Rc::new(Void))
+            }
+            _ => Err(EvaluationError::no_member(self.type_matcher(), name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::IntegerExpression;
+    use crate::ast::v_string::{StringExpression, StringValue};
+
+    #[test]
+    fn test_list_evaluate() {
+        evaluates_to(
+            ListExpression::new(vec![
+                IntegerExpression::rc(1),
+                StringExpression::rc("a".to_string()),
+            ]).evaluate(&mut Scope::new()),
+            ListValue::rc_value(vec![
+                IntegerValue::rc_value(1),
+                StringValue::rc_value("a".to_string()),
+            ]));
+    }
+
+    #[test]
+    fn test_list_index() {
+        let list = ListValue::rc_value(vec![
+            IntegerValue::rc_value(1),
+            IntegerValue::rc_value(2),
+        ]);
+        evaluates_to(list.index(IntegerValue::rc_value(1)), IntegerValue::rc_value(2));
+        list.assign_index(IntegerValue::rc_value(1), IntegerValue::rc_value(5)).unwrap();
+        evaluates_to(list.index(IntegerValue::rc_value(1)), IntegerValue::rc_value(5));
+        errors_to(list.index(IntegerValue::rc_value(2)), "Index 2 out of range for length 2");
+        errors_to(list.index(IntegerValue::rc_value(-1)), "Index -1 out of range for length 2");
+        errors_to(list.index(StringValue::rc_value("a".to_string())), "Can't index List with String");
+    }
+
+    #[test]
+    fn test_list_display() {
+        assert_eq!(format!("{}", ListValue::rc_value(vec![]).type_matcher()), "[]");
+        assert_eq!(
+            format!("{}", ListValue::rc_value(vec![
+                IntegerValue::rc_value(1),
+                StringValue::rc_value("a, b".to_string()),
+                ListValue::rc_value(vec![IntegerValue::rc_value(2)]),
+            ]).type_matcher()),
+            "[1, \"a, b\", [2]]");
+    }
+
+    #[test]
+    fn test_list_containing_itself() {
+        let list = ListValue::rc_value(vec![IntegerValue::rc_value(1)]);
+        list.assign_index(IntegerValue::rc_value(0), list.clone()).unwrap();
+        assert_eq!(format!("{}", list.type_matcher()), "[[...]]");
+        assert_eq!(format!("{:?}", list.type_matcher()), "List([List([...])])");
+        assert_eq!(list.type_matcher(), list.type_matcher());
+        let other = ListValue::rc_value(vec![list.clone(), list.clone()]);
+        assert_eq!(format!("{}", other.type_matcher()), "[[[...]], [[...]]]");
+        evaluates_to(list.apply_equals(list.clone()), BooleanValue::rc(true));
+    }
+
+    #[test]
+    fn test_list_equality() {
+        let list = |values: Vec<i32>| ListValue::rc_value(values.into_iter().map(IntegerValue::rc_value).collect());
+        evaluates_to(list(vec![1, 2]).apply_equals(list(vec![1, 2])), BooleanValue::rc(true));
+        evaluates_to(list(vec![1, 2]).apply_equals(list(vec![2, 1])), BooleanValue::rc(false));
+        evaluates_to(list(vec![1, 2]).apply_equals(list(vec![1])), BooleanValue::rc(false));
+        evaluates_to(list(vec![]).apply_not_equals(list(vec![1])), BooleanValue::rc(true));
+        errors_to(list(vec![1]).apply_equals(IntegerValue::rc_value(1)), "Can't apply List eq/neq Integer");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,182 @@
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher, Entries, MapEntry, HashKey, visit_once};
+use crate::ast::v_boolean::BooleanValue;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub struct MapExpression {
+    entries: Vec<(Rc<dyn Expression>, Rc<dyn Expression>)>,
+}
+
+impl MapExpression {
+    pub fn new(entries: Vec<(Rc<dyn Expression>, Rc<dyn Expression>)>) -> MapExpression {
+        MapExpression {
+            entries,
+        }
+    }
+    pub fn rc(entries: Vec<(Rc<dyn Expression>, Rc<dyn Expression>)>) -> Rc<MapExpression> {
+        Rc::new(MapExpression::new(entries))
+    }
+}
+
+impl Expression for MapExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let map = MapValue::new();
+        for (key, value) in &self.entries {
+            let key = key.evaluate(scope)?;
+            let value = value.evaluate(scope)?;
+            map.insert(key, value)?;
+        }
+        Ok(Rc::new(map))
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} Map", "-".repeat(level));
+        for (key, value) in &self.entries {
+            println!("{} Key", "-".repeat(level + 1));
+            key.visualize(level + 2);
+            println!("{} Value", "-".repeat(level + 1));
+            value.visualize(level + 2);
+        }
+    }
+}
+
+/// Maps are shared by reference like lists. Entries are kept in insertion order,
+/// their positions are looked up by HashKey.
+pub struct MapValue {
+    positions: RefCell<HashMap<HashKey, usize>>,
+    entries: RefCell<Vec<MapEntry>>,
+}
+
+impl MapValue {
+    fn new() -> MapValue {
+        MapValue {
+            positions: RefCell::new(HashMap::new()),
+            entries: RefCell::new(vec![]),
+        }
+    }
+
+    fn insert(&self, key: Rc<dyn Value>, value: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        let hash_key = key.hash_key()?;
+        let mut entries = self.entries.borrow_mut();
+        let mut positions = self.positions.borrow_mut();
+        match positions.get(&hash_key) {
+            Some(position) => entries[*position].1 = value,
+            None => {
+                positions.insert(hash_key, entries.len());
+                entries.push((key, value));
+            }
+        }
+        Ok(())
+    }
+
+    fn get(&self, key: &Rc<dyn Value>) -> Result<Option<Rc<dyn Value>>, EvaluationError> {
+        let position = self.positions.borrow().get(&key.hash_key()?).copied();
+        Ok(position.map(|position| self.entries.borrow()[position].1.clone()))
+    }
+
+    /// Maps are equal when they have the same keys with equal values, in any order
+    fn equals(&self, other: &Rc<dyn Value>) -> Result<bool, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Map(Entries(other_entries)) => {
+                // Map containing itself is equal to itself as far as the other entries are
+                let addresses = (self.entries.as_ptr() as usize, other_entries.as_ptr() as usize);
+                visit_once(addresses, || {
+                    if self.entries.borrow().len() != other_entries.borrow().len() {
+                        return Ok(false);
+                    }
+                    for (key, theirs) in other_entries.borrow().iter() {
+                        match self.get(key)? {
+                            Some(mine) if mine.apply_equals(theirs.clone())?.is_truthy() => (),
+                            _ => return Ok(false),
+                        }
+                    }
+                    Ok(true)
+                }).unwrap_or(Ok(true))
+            }
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+}
+
+impl Value for MapValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Map(Entries(&self.entries))
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(self.equals(&other)?))
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(!self.equals(&other)?))
+    }
+    fn iterate(&self) -> Result<Box<dyn Iterator<Item = Rc<dyn Value>>>, EvaluationError> {
+        let keys: Vec<Rc<dyn Value>> = self.entries.borrow().iter()
+            .map(|(key, _)| key.clone())
+            .collect();
+        Ok(Box::new(keys.into_iter()))
+    }
+    fn index(&self, index: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match self.get(&index)? {
+            Some(value) => Ok(value),
+            None => Err(EvaluationError::key_not_found(&index)),
+        }
+    }
+    fn assign_index(&self, index: Rc<dyn Value>, element: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        self.insert(index, element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::IntegerValue;
+    use crate::ast::v_string::StringValue;
+    use crate::ast::v_float::FloatValue;
+
+    fn string(value: &str) -> Rc<dyn Value> {
+        StringValue::rc_value(value.to_string())
+    }
+
+    #[test]
+    fn test_map_insert_and_lookup() {
+        let map = MapValue::new();
+        map.assign_index(string("a"), IntegerValue::rc_value(1)).unwrap();
+        map.assign_index(IntegerValue::rc_value(1), IntegerValue::rc_value(2)).unwrap();
+        evaluates_to(map.index(string("a")), IntegerValue::rc_value(1));
+        evaluates_to(map.index(IntegerValue::rc_value(1)), IntegerValue::rc_value(2));
+        map.assign_index(string("a"), IntegerValue::rc_value(3)).unwrap();
+        evaluates_to(map.index(string("a")), IntegerValue::rc_value(3));
+        errors_to(map.index(string("b")), "Key \"b\" not found");
+        errors_to(map.index(FloatValue::rc_value(1.0)), "Float can't be used as map key");
+    }
+
+    #[test]
+    fn test_map_keeps_insertion_order() {
+        let map = MapValue::new();
+        map.assign_index(string("b"), IntegerValue::rc_value(1)).unwrap();
+        map.assign_index(string("a"), IntegerValue::rc_value(2)).unwrap();
+        map.assign_index(string("b"), IntegerValue::rc_value(3)).unwrap();
+        assert_eq!(format!("{}", map.type_matcher()), "{\"b\": 3, \"a\": 2}");
+        let keys: Vec<Rc<dyn Value>> = map.iterate().unwrap().collect();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].type_matcher(), TypeMatcher::String("b"));
+        assert_eq!(keys[1].type_matcher(), TypeMatcher::String("a"));
+    }
+
+    #[test]
+    fn test_map_equality() {
+        let map = MapValue::new();
+        map.assign_index(string("a"), IntegerValue::rc_value(1)).unwrap();
+        let other = MapValue::new();
+        other.assign_index(string("a"), IntegerValue::rc_value(1)).unwrap();
+        let other: Rc<dyn Value> = Rc::new(other);
+        evaluates_to(map.apply_equals(other.clone()), BooleanValue::rc(true));
+        other.assign_index(string("b"), IntegerValue::rc_value(1)).unwrap();
+        evaluates_to(map.apply_not_equals(other), BooleanValue::rc(true));
+        errors_to(map.apply_equals(IntegerValue::rc_value(1)), "Can't apply Map eq/neq Integer");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use crate::ast::{Value, EvaluationError, TypeMatcher};
+use crate::ast::scope::Scope;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use crate::bogusstd::prepare_scope;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::fs;
+
+/// Modules loaded by the program, each module is evaluated only once
+#[derive(Default)]
+pub struct ModuleRegistry {
+    loaded: HashMap<PathBuf, Rc<ModuleValue>>,
+    /// Modules being evaluated, the innermost last
+    loading: Vec<PathBuf>,
+}
+
+impl ModuleRegistry {
+    /// Registry of a program whose main file is being evaluated
+    pub fn loading(path: PathBuf) -> ModuleRegistry {
+        ModuleRegistry {
+            loaded: HashMap::new(),
+            loading: vec![path],
+        }
+    }
+}
+
+/// Evaluated module, its members are the bindings declared at the top level of the module
+pub struct ModuleValue {
+    name: String,
+    scope: Scope,
+}
+
+impl ModuleValue {
+    /// Top level bindings of the module
+    pub fn exports(&self) -> &Scope {
+        &self.scope
+    }
+}
+
+impl Value for ModuleValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Module(&self.name)
+    }
+    fn get_member(&self, name: &str) -> Result<Rc<dyn Value>, EvaluationError> {
+        match self.scope.resolve_local(name) {
+            Some(value) => Ok(value),
+            None => Err(EvaluationError::no_member(self.type_matcher(), name)),
+        }
+    }
+    fn assign_member(&self, name: &str, _member: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        Err(EvaluationError::read_only_module(name))
+    }
+    /// Modules have no methods, `m.f(x)` calls function `f` of the module
+    fn call_method(&self, name: &str, args: Vec<Rc<dyn Value>>) -> Result<Rc<dyn Value>, EvaluationError> {
+        self.call_method_named(name, args, vec![])
+    }
+    fn call_method_named(&self,
+                         name: &str,
+                         args: Vec<Rc<dyn Value>>,
+                         named: Vec<(String, Rc<dyn Value>)>) -> Result<Rc<dyn Value>, EvaluationError> {
+        self.get_member(name)?.call(&mut self.scope.clone(), args, named)
+    }
+}
+
+/// Loads module from path relative to the file of given scope, or returns the already loaded module
+pub fn load_module(scope: &Scope, import_path: &str) -> Result<Rc<ModuleValue>, EvaluationError> {
+    let base = scope.module().path.as_ref().and_then(|path| path.parent()).unwrap_or(Path::new("."));
+    let path = base.join(import_path).canonicalize()
+        .map_err(|error| EvaluationError::cant_import(import_path, &error.to_string()))?;
+
+    let registry = scope.module().registry.clone();
+    if let Some(module) = registry.borrow().loaded.get(&path) {
+        return Ok(module.clone());
+    }
+    if let Some(start) = registry.borrow().loading.iter().position(|loading| *loading == path) {
+        let mut cycle = registry.borrow().loading[start..].to_vec();
+        cycle.push(path);
+        return Err(EvaluationError::import_cycle(&cycle));
+    }
+
+    registry.borrow_mut().loading.push(path.clone());
+    let module = evaluate_module(scope, import_path, &path);
+    registry.borrow_mut().loading.pop();
+
+    let module = Rc::new(module?);
+    registry.borrow_mut().loaded.insert(path, module.clone());
+    Ok(module)
+}
+
+/// Evaluates module file in a fresh scope that has only the standard library
+fn evaluate_module(scope: &Scope, import_path: &str, path: &Path) -> Result<ModuleValue, EvaluationError> {
+    let cant_import = |reason: String| EvaluationError::cant_import(import_path, &reason);
+    let source = fs::read_to_string(path).map_err(|error| cant_import(error.to_string()))?;
+    let mut lexer = Lexer::new(&source).map_err(|error| cant_import(error.to_string()))?;
+    let expressions = Parser::new(&mut lexer).parse().map_err(|error| cant_import(error.to_string()))?;
+
+    let mut std_scope = scope.for_module(path.to_path_buf());
+    prepare_scope(&mut std_scope);
+    let mut module_scope = Scope::sub(&std_scope);
+    for expression in expressions {
+        // Runtime errors tell which module failed, thrown values can still be caught as they are
+        expression.evaluate(&mut module_scope).map_err(|error| match error.thrown {
+            Some(_) => error,
+            None => error.in_module(import_path),
+        })?;
+    }
+    Ok(ModuleValue {
+        name: path.display().to_string(),
+        scope: module_scope,
+    })
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::ast::{Value, TypeMatcher, Expression, EvaluationError};
 use std::rc::Rc;
 use crate::ast::scope::Scope;
+use crate::ast::v_boolean::BooleanValue;
 
 pub struct Null;
 
@@ -18,7 +19,28 @@ impl Expression for Null {
 }
 
 impl Value for Null {
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         TypeMatcher::Null
     }
+    fn is_truthy(&self) -> bool {
+        false
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Null => Ok(BooleanValue::rc(true)),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Null => Ok(BooleanValue::rc(false)),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
 }
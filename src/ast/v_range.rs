@@ -0,0 +1,87 @@
+use crate::ast::{Value, EvaluationError, TypeMatcher};
+use crate::ast::v_integer::IntegerValue;
+use crate::ast::v_boolean::BooleanValue;
+use std::rc::Rc;
+
+pub struct RangeValue {
+    start: i32,
+    end: i32,
+    inclusive: bool,
+}
+
+impl RangeValue {
+    pub fn rc_value(start: i32, end: i32, inclusive: bool) -> Rc<dyn Value> {
+        Rc::new(RangeValue { start, end, inclusive })
+    }
+
+    /// Ranges are equal when they have the same start and end and both are inclusive or not
+    fn equals(&self, other: &Rc<dyn Value>) -> Result<bool, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Range(start, end, inclusive) =>
+                Ok(*start == self.start && *end == self.end && inclusive == self.inclusive),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+}
+
+impl Value for RangeValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Range(&self.start, &self.end, self.inclusive)
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(self.equals(&other)?))
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(!self.equals(&other)?))
+    }
+    fn iterate(&self) -> Result<Box<dyn Iterator<Item = Rc<dyn Value>>>, EvaluationError> {
+        // Ranges are lazy, 0..1000000 does not allocate million integers
+        if self.inclusive {
+            Ok(Box::new((self.start..=self.end).map(IntegerValue::rc_value)))
+        } else {
+            Ok(Box::new((self.start..self.end).map(IntegerValue::rc_value)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+
+    fn iterates_to(value: Rc<dyn Value>, expected: Vec<i32>) {
+        let values: Vec<Rc<dyn Value>> = value.iterate().unwrap().collect();
+        let expected: Vec<TypeMatcher> = expected.iter().map(TypeMatcher::Integer).collect();
+        assert_eq!(values.iter().map(|v| v.type_matcher()).collect::<Vec<TypeMatcher>>(), expected);
+    }
+
+    #[test]
+    fn test_range_iterate() {
+        iterates_to(RangeValue::rc_value(0, 3, false), vec![0, 1, 2]);
+        iterates_to(RangeValue::rc_value(0, 3, true), vec![0, 1, 2, 3]);
+        iterates_to(RangeValue::rc_value(3, 3, false), vec![]);
+        iterates_to(RangeValue::rc_value(3, 0, false), vec![]);
+    }
+
+    #[test]
+    fn test_range_equality() {
+        evaluates_to(RangeValue::rc_value(0, 3, false).apply_equals(RangeValue::rc_value(0, 3, false)),
+                     BooleanValue::rc(true));
+        evaluates_to(RangeValue::rc_value(0, 3, false).apply_equals(RangeValue::rc_value(0, 4, false)),
+                     BooleanValue::rc(false));
+        evaluates_to(RangeValue::rc_value(0, 3, false).apply_not_equals(RangeValue::rc_value(0, 3, true)),
+                     BooleanValue::rc(true));
+        errors_to(RangeValue::rc_value(0, 3, false).apply_equals(IntegerValue::rc_value(0)),
+                  "Can't apply Range eq/neq Integer");
+    }
+
+    #[test]
+    fn test_range_display() {
+        assert_eq!(format!("{}", RangeValue::rc_value(0, 3, false).type_matcher()), "0..3");
+        assert_eq!(format!("{}", RangeValue::rc_value(0, 3, true).type_matcher()), "0..=3");
+    }
+}
\ No newline at end of file
@@ -1,5 +1,12 @@
-use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher};
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher, HashKey, check_arity};
+use crate::ast::v_integer::IntegerValue;
+use crate::ast::v_list::ListValue;
+use crate::ast::v_boolean::BooleanValue;
 use std::rc::Rc;
+use std::cmp::Ordering;
+
+/// Longest string in bytes that repetition may create, larger strings are most likely mistakes
+const MAX_REPEATED_LENGTH: usize = 1 << 26;
 
 pub struct StringExpression {
     value: String,
@@ -39,16 +46,101 @@ impl StringValue {
 }
 
 impl Value for StringValue {
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         TypeMatcher::String(&self.value)
     }
+    fn hash_key(&self) -> Result<HashKey, EvaluationError> {
+        Ok(HashKey::String(self.value.clone()))
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::String(other_value) => Ok(BooleanValue::rc(self.value == other_value)),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::String(other_value) => Ok(BooleanValue::rc(self.value != other_value)),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    /// String concatenation. Any value is converted to string when right side
+    /// is not a string: "n=" + 5 -> "n=5". Note that 5 + "a" is still an error.
+    fn apply_plus(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(StringValue::rc_value(format!("{}{}", self.value, other.type_matcher())))
+    }
+    /// String repetition: "-" * 3 -> "---"
+    fn apply_multiplication(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Integer(times) if *times >= 0 => {
+                match self.value.len().checked_mul(*times as usize) {
+                    Some(length) if length <= MAX_REPEATED_LENGTH =>
+                        Ok(StringValue::rc_value(self.value.repeat(*times as usize))),
+                    _ => Err(EvaluationError::new(format!("Can't repeat String {} times, result is too long", times))),
+                }
+            }
+            TypeMatcher::Integer(times) =>
+                Err(EvaluationError::new(format!("Can't repeat String {} times", times))),
+            _ => Err(EvaluationError::operator_not_applicable(
+                "*",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+    fn compare(&self, other: &Rc<dyn Value>) -> Option<Ordering> {
+        match other.type_matcher() {
+            TypeMatcher::String(other_value) => Some(self.value.as_str().cmp(other_value)),
+            _ => None,
+        }
+    }
+    fn iterate(&self) -> Result<Box<dyn Iterator<Item = Rc<dyn Value>>>, EvaluationError> {
+        let chars: Vec<Rc<dyn Value>> = self.value.chars()
+            .map(|c| StringValue::rc_value(c.to_string()))
+            .collect();
+        Ok(Box::new(chars.into_iter()))
+    }
+    fn call_method(&self, name: &str, args: Vec<Rc<dyn Value>>) -> Result<Rc<dyn Value>, EvaluationError> {
+        match name {
+            "len" => {
+                check_arity(0, &args)?;
+                Ok(IntegerValue::rc_value(self.value.chars().count() as i32))
+            }
+            "upper" => {
+                check_arity(0, &args)?;
+                Ok(StringValue::rc_value(self.value.to_uppercase()))
+            }
+            "split" => {
+                check_arity(1, &args)?;
+                match args[0].type_matcher() {
+                    // Splitting with empty separator splits to characters like iteration does
+                    TypeMatcher::String("") => Ok(ListValue::rc_value(self.iterate()?.collect())),
+                    TypeMatcher::String(separator) => Ok(ListValue::rc_value(
+                        self.value.split(separator)
+                            .map(|part| StringValue::rc_value(part.to_string()))
+                            .collect())),
+                    other => Err(EvaluationError::operator_not_applicable(
+                        "split",
+                        self.type_matcher(),
+                        other)),
+                }
+            }
+            _ => Err(EvaluationError::no_member(self.type_matcher(), name)),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
-    use crate::ast::tests::evaluates_to;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_boolean::BooleanValue;
 
     #[test]
     fn test_string_equals() {
@@ -62,6 +154,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_string_operators() {
+        evaluates_to(
+            StringValue::rc_value("a".to_string()).apply_plus(StringValue::rc_value("b".to_string())),
+            StringValue::rc_value("ab".to_string()));
+        evaluates_to(
+            StringValue::rc_value("n=".to_string()).apply_plus(IntegerValue::rc_value(5)),
+            StringValue::rc_value("n=5".to_string()));
+        evaluates_to(
+            StringValue::rc_value("ab".to_string()).apply_equals(StringValue::rc_value("ab".to_string())),
+            BooleanValue::rc(true));
+        evaluates_to(
+            StringValue::rc_value("ab".to_string()).apply_not_equals(StringValue::rc_value("ab".to_string())),
+            BooleanValue::rc(false));
+        evaluates_to(
+            StringValue::rc_value("-".to_string()).apply_multiplication(IntegerValue::rc_value(3)),
+            StringValue::rc_value("---".to_string()));
+        errors_to(
+            StringValue::rc_value("-".to_string()).apply_multiplication(IntegerValue::rc_value(-1)),
+            "Can't repeat String -1 times");
+        errors_to(
+            StringValue::rc_value("ab".to_string()).apply_multiplication(IntegerValue::rc_value(2000000000)),
+            "Can't repeat String 2000000000 times, result is too long");
+        evaluates_to(
+            StringValue::rc_value("".to_string()).apply_multiplication(IntegerValue::rc_value(i32::MAX)),
+            StringValue::rc_value("".to_string()));
+        errors_to(
+            StringValue::rc_value("a".to_string()).apply_equals(IntegerValue::rc_value(1)),
+            "Can't apply String eq/neq Integer");
+        errors_to(
+            StringValue::rc_value("a".to_string()).apply_minus(StringValue::rc_value("a".to_string())),
+            "Can't apply String - String");
+    }
+
+    #[test]
+    fn test_string_comparison() {
+        evaluates_to(
+            StringValue::rc_value("a".to_string()).apply_less_than(StringValue::rc_value("b".to_string())),
+            BooleanValue::rc(true));
+        evaluates_to(
+            StringValue::rc_value("ab".to_string()).apply_greater_than(StringValue::rc_value("a".to_string())),
+            BooleanValue::rc(true));
+        evaluates_to(
+            StringValue::rc_value("b".to_string()).apply_less_or_equals(StringValue::rc_value("abc".to_string())),
+            BooleanValue::rc(false));
+        errors_to(
+            StringValue::rc_value("a".to_string()).apply_greater_or_equals(IntegerValue::rc_value(1)),
+            "Can't apply String >= Integer");
+    }
+
+    #[test]
+    fn test_string_iterate() {
+        let chars: Vec<Rc<dyn Value>> = StringValue::rc_value("äb".to_string())
+            .iterate().unwrap().collect();
+        assert_eq!(chars.len(), 2);
+        assert_eq!(chars[0].type_matcher(), TypeMatcher::String("ä"));
+        assert_eq!(chars[1].type_matcher(), TypeMatcher::String("b"));
+    }
+
+    #[test]
+    fn test_string_methods() {
+        evaluates_to(
+            StringValue::rc_value("äbc".to_string()).call_method("len", vec![]),
+            IntegerValue::rc_value(3));
+        evaluates_to(
+            StringValue::rc_value("abc".to_string()).call_method("upper", vec![]),
+            StringValue::rc_value("ABC".to_string()));
+        evaluates_to(
+            StringValue::rc_value("a,b".to_string()).call_method("split", vec![StringValue::rc_value(",".to_string())]),
+            ListValue::rc_value(vec![
+                StringValue::rc_value("a".to_string()),
+                StringValue::rc_value("b".to_string()),
+            ]));
+        errors_to(
+            StringValue::rc_value("a".to_string()).call_method("split", vec![]),
+            "Expecting 1 arguments for call but 0 given");
+        errors_to(
+            StringValue::rc_value("a".to_string()).call_method("split", vec![IntegerValue::rc_value(1)]),
+            "Can't apply String split Integer");
+        errors_to(
+            StringValue::rc_value("a".to_string()).call_method("foo", vec![]),
+            "String has no member `foo`");
+    }
+
     #[test]
     fn test_string_evaluate() {
         let integer_expr = StringExpression::new("foo".to_string());
@@ -0,0 +1,164 @@
+use crate::ast::{Expression, Scope, Value, EvaluationError, TypeMatcher, Fields, StructField, visit_once};
+use crate::ast::v_boolean::BooleanValue;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+/// Struct construction `Point { x: 1, y: 2 }`
+pub struct StructExpression {
+    name: String,
+    fields: Vec<(String, Rc<dyn Expression>)>,
+}
+
+impl StructExpression {
+    pub fn new(name: String, fields: Vec<(String, Rc<dyn Expression>)>) -> StructExpression {
+        StructExpression {
+            name,
+            fields,
+        }
+    }
+    pub fn rc(name: String, fields: Vec<(String, Rc<dyn Expression>)>) -> Rc<StructExpression> {
+        Rc::new(StructExpression::new(name, fields))
+    }
+}
+
+impl Expression for StructExpression {
+    fn evaluate(&self, scope: &mut Scope) -> Result<Rc<dyn Value>, EvaluationError> {
+        let struct_type = scope.resolve_result(&self.name)?;
+        let mut values = vec![];
+        for (name, expression) in &self.fields {
+            values.push((name.clone(), expression.evaluate(scope)?));
+        }
+        struct_type.construct(values)
+    }
+    fn visualize(&self, level: usize) {
+        println!("{} Struct {}", "-".repeat(level), self.name);
+        for (name, expression) in &self.fields {
+            println!("{} Field({})", "-".repeat(level + 1), name);
+            expression.visualize(level + 2);
+        }
+    }
+}
+
+/// Structs are shared by reference like lists, `p.x = 1` is visible through every binding
+pub struct StructValue {
+    name: String,
+    fields: RefCell<Vec<StructField>>,
+}
+
+impl StructValue {
+    /// Fields must be the declared fields in declaration order
+    pub fn rc_value(name: String, fields: Vec<StructField>) -> Rc<dyn Value> {
+        Rc::new(StructValue { name, fields: RefCell::new(fields) })
+    }
+
+    fn position(&self, name: &str) -> Result<usize, EvaluationError> {
+        match self.fields.borrow().iter().position(|(field, _)| field == name) {
+            Some(position) => Ok(position),
+            None => Err(EvaluationError::no_member(self.type_matcher(), name)),
+        }
+    }
+
+    /// Tells if other fields have the same names as fields of this struct, in the same order
+    fn same_field_names(&self, other_fields: &RefCell<Vec<StructField>>) -> bool {
+        let (fields, others) = (self.fields.borrow(), other_fields.borrow());
+        fields.len() == others.len() &&
+            fields.iter().zip(others.iter()).all(|((mine, _), (theirs, _))| mine == theirs)
+    }
+
+    /// Structs are equal when they are of the same struct and their fields are equal.
+    /// Structs of the same name declared in different scopes must have the same fields.
+    fn equals(&self, other: &Rc<dyn Value>) -> Result<bool, EvaluationError> {
+        match other.type_matcher() {
+            TypeMatcher::Struct(name, Fields(other_fields))
+                if name == self.name && self.same_field_names(other_fields) => {
+                // Struct containing itself is equal to itself as far as the other fields are
+                let addresses = (self.fields.as_ptr() as usize, other_fields.as_ptr() as usize);
+                visit_once(addresses, || {
+                    for ((_, mine), (_, theirs)) in self.fields.borrow().iter().zip(other_fields.borrow().iter()) {
+                        if !mine.apply_equals(theirs.clone())?.is_truthy() {
+                            return Ok(false);
+                        }
+                    }
+                    Ok(true)
+                }).unwrap_or(Ok(true))
+            }
+            _ => Err(EvaluationError::operator_not_applicable(
+                "eq/neq",
+                self.type_matcher(),
+                other.type_matcher()))
+        }
+    }
+}
+
+impl Value for StructValue {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
+        TypeMatcher::Struct(&self.name, Fields(&self.fields))
+    }
+    fn apply_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(self.equals(&other)?))
+    }
+    fn apply_not_equals(&self, other: Rc<dyn Value>) -> Result<Rc<dyn Value>, EvaluationError> {
+        Ok(BooleanValue::rc(!self.equals(&other)?))
+    }
+    fn get_member(&self, name: &str) -> Result<Rc<dyn Value>, EvaluationError> {
+        let position = self.position(name)?;
+        Ok(self.fields.borrow()[position].1.clone())
+    }
+    fn assign_member(&self, name: &str, member: Rc<dyn Value>) -> Result<(), EvaluationError> {
+        let position = self.position(name)?;
+        self.fields.borrow_mut()[position].1 = member;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use crate::ast::tests::{evaluates_to, errors_to};
+    use crate::ast::v_integer::IntegerValue;
+    use crate::ast::v_string::StringValue;
+
+    fn point(x: i32, y: i32) -> Rc<dyn Value> {
+        StructValue::rc_value("Point".to_string(), vec![
+            ("x".to_string(), IntegerValue::rc_value(x)),
+            ("y".to_string(), IntegerValue::rc_value(y)),
+        ])
+    }
+
+    #[test]
+    fn test_struct_members() {
+        let p = point(1, 2);
+        evaluates_to(p.get_member("y"), IntegerValue::rc_value(2));
+        p.assign_member("y", IntegerValue::rc_value(5)).unwrap();
+        evaluates_to(p.get_member("y"), IntegerValue::rc_value(5));
+        errors_to(p.get_member("z"), "Point has no member `z`");
+        assert!(p.assign_member("z", IntegerValue::rc_value(5)).is_err());
+    }
+
+    #[test]
+    fn test_struct_equality() {
+        evaluates_to(point(1, 2).apply_equals(point(1, 2)), BooleanValue::rc(true));
+        evaluates_to(point(1, 2).apply_equals(point(1, 3)), BooleanValue::rc(false));
+        evaluates_to(point(1, 2).apply_not_equals(point(1, 3)), BooleanValue::rc(true));
+        let other = StructValue::rc_value("Other".to_string(), vec![
+            ("x".to_string(), IntegerValue::rc_value(1)),
+        ]);
+        errors_to(point(1, 2).apply_equals(other), "Can't apply Point eq/neq Other");
+        let other_point = StructValue::rc_value("Point".to_string(), vec![
+            ("y".to_string(), IntegerValue::rc_value(1)),
+            ("x".to_string(), IntegerValue::rc_value(2)),
+        ]);
+        errors_to(point(1, 2).apply_equals(other_point), "Can't apply Point eq/neq Point");
+        errors_to(point(1, 2).apply_equals(StringValue::rc_value("a".to_string())),
+                  "Can't apply Point eq/neq String");
+    }
+
+    #[test]
+    fn test_struct_display() {
+        let p = StructValue::rc_value("Named".to_string(), vec![
+            ("name".to_string(), StringValue::rc_value("a".to_string())),
+        ]);
+        assert_eq!(format!("{}", p.type_matcher()), "Named { name: \"a\" }");
+    }
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use crate::ast::{Value, TypeMatcher};
 pub struct Void;
 
 impl Value for Void {
-    fn type_matcher(&self) -> TypeMatcher {
+    fn type_matcher(&self) -> TypeMatcher<'_> {
         TypeMatcher::Void
     }
 }
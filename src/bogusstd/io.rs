@@ -1,7 +1,6 @@
 use std::rc::Rc;
 use crate::ast::{Value, Expression, EvaluationError};
-use crate::ast::s_fun::Function;
-use crate::ast::e_identifier::IdentifierExpression;
+use crate::ast::s_fun::{Function, Parameter};
 use crate::ast::scope::Scope;
 use crate::ast::v_void::Void;
 
@@ -25,15 +24,24 @@ impl Expression for RustExpression {
     }
 }
 
+/// Displays all arguments collected to rest parameter separated by space
+fn join_args(scope: &mut Scope) -> Result<String, EvaluationError> {
+    let args: Vec<String> = scope.resolve_result(&"args".to_string())?
+        .iterate()?
+        .map(|arg| format!("{}", arg.type_matcher()))
+        .collect();
+    Ok(args.join(" "))
+}
+
 pub fn io_functions() -> [NameAndValue; 2] {
     [
         NameAndValue {
             name: "print".to_string(),
             value: Function::rc(
-                vec![IdentifierExpression::new("a".to_string())],
+                vec![Parameter::rest("args".to_string())],
                 Rc::new(RustExpression {
                     native_hook: |scope| {
-                        print!("{}", scope.resolve_result(&"a".to_string())?.type_matcher());
+                        print!("{}", join_args(scope)?);
                         Ok(Rc::new(Void))
                     }
                 }),
@@ -42,10 +50,10 @@ pub fn io_functions() -> [NameAndValue; 2] {
         NameAndValue {
             name: "println".to_string(),
             value: Function::rc(
-                vec![IdentifierExpression::new("a".to_string())],
+                vec![Parameter::rest("args".to_string())],
                 Rc::new(RustExpression {
                     native_hook: |scope| {
-                        println!("{}", scope.resolve_result(&"a".to_string())?.type_matcher());
+                        println!("{}", join_args(scope)?);
                         Ok(Rc::new(Void))
                     }
                 }),
@@ -1,6 +1,6 @@
 pub mod tokens;
 
-use tokens::{Token, TokenKind, SourceRef };
+use tokens::{Token, TokenKind, SourceRef, StrPart};
 
 use core::fmt;
 use crate::lexer::ShouldContinue::{BailOut, Continue};
@@ -9,6 +9,8 @@ use crate::lexer::ShouldContinue::{BailOut, Continue};
 pub struct Lexer {
     tokens: Vec<Token>,
     pointer: usize,
+    /// Parser setting, struct construction is not parsed in heads of if, while, for and match
+    structs: bool,
 }
 
 impl Lexer {
@@ -21,7 +23,10 @@ impl Lexer {
             current_line: 1,
             current_column: 0,
             token_column_marker: 0,
-            string_escape_flag: false,
+            string_escape: StringEscape::None,
+            interpolation: InterpolationState::default(),
+            block_comment: BlockCommentState::default(),
+            doc: None,
         };
         let mut character_iter = source.chars().peekable();
         while let Some(char) = character_iter.next() {
@@ -30,12 +35,31 @@ impl Lexer {
                 tokens.push(token);
             }
         }
+        if buffer.mode == LexingState::BlockComment {
+            return Err(LexingError {
+                msg: "block comment is not terminated".to_string(),
+                location: SourceRef {
+                    line: buffer.block_comment.line,
+                    column: buffer.block_comment.column,
+                },
+            });
+        }
         Ok(Lexer {
             pointer: 0,
             tokens,
+            structs: true,
         })
     }
 
+    /// Lexer over already lexed tokens, used for expressions in interpolated strings
+    pub fn from_tokens(tokens: Vec<Token>) -> Lexer {
+        Lexer {
+            pointer: 0,
+            tokens,
+            structs: true,
+        }
+    }
+
     /// Advances lexer and returns next token
     pub fn next(&mut self) -> Option<&Token> {
         let token = self.tokens.get(self.pointer);
@@ -61,6 +85,11 @@ impl Lexer {
         self.tokens.get(self.pointer)
     }
 
+    /// Peeks nth token after the next one without advancing the lexer, peek_nth(0) equals peek()
+    pub fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.pointer + n)
+    }
+
     /// Returns peek() and wraps None to UnexpectedEOFError
     pub fn peek_or_err(&mut self) -> Result<&Token, UnexpectedEOFError> {
         match self.peek() {
@@ -69,9 +98,19 @@ impl Lexer {
         }
     }
 
+    /// Enables or disables parsing of struct construction, returns the previous setting
+    pub fn allow_structs(&mut self, allowed: bool) -> bool {
+        std::mem::replace(&mut self.structs, allowed)
+    }
+
+    /// Tells if `Name { field: value }` is parsed as struct construction
+    pub fn structs_allowed(&self) -> bool {
+        self.structs
+    }
+
     /// Tells if lexer has next token
     pub fn has_next(&self) -> bool {
-        self.pointer + 1 <= self.tokens.len()
+        self.pointer < self.tokens.len()
     }
 }
 
@@ -104,7 +143,44 @@ enum LexingState {
     Integer,
     Float,
     String,
+    Interpolation,
     LineComment,
+    BlockComment,
+}
+
+/// Bookkeeping for nestable block comments
+#[derive(Default)]
+struct BlockCommentState {
+    depth: u32,
+    /// Previous character, /* and */ span two characters
+    previous: Option<char>,
+    /// Where the outermost comment opened, for errors
+    line: u32,
+    column: u32,
+}
+
+/// Escape sequence in string literal, column is where the sequence started.
+/// Unicode escape is None until the opening brace of \u{XXXX}.
+#[derive(PartialEq, Debug)]
+enum StringEscape {
+    None,
+    Started(u32),
+    Unicode(u32, Option<String>),
+}
+
+/// Bookkeeping for `${...}` expressions inside string literals
+#[derive(Default)]
+struct InterpolationState {
+    /// Parts of the current string literal so far
+    parts: Vec<StrPart>,
+    /// Depth of braces, closing brace of depth 1 ends the expression
+    depth: u32,
+    /// Expression can contain string literals, braces in them are not counted
+    in_string: bool,
+    escape_flag: bool,
+    /// Where the expression starts in the source, for offsetting nested tokens
+    line: u32,
+    column: u32,
 }
 
 /// LexBuffer is used for stateful lexing the given input
@@ -114,7 +190,11 @@ struct LexBuffer {
     current_line: u32,
     current_column: u32,
     token_column_marker: u32,
-    string_escape_flag: bool,
+    string_escape: StringEscape,
+    interpolation: InterpolationState,
+    block_comment: BlockCommentState,
+    /// Doc comment waiting to be attached to the next token
+    doc: Option<String>,
 }
 
 /// Utility enum for LexBuffer, for determining whether lexer should
@@ -136,6 +216,21 @@ impl LexBuffer {
             return Ok(None);
         }
 
+        // Escape sequences are handled separately, because they can fail
+        if self.string_escape != StringEscape::None {
+            self.push_escaped(current_char)?;
+            if peek.is_none() {
+                return Err(LexingError {
+                    msg: "string is not terminated".to_string(),
+                    location: SourceRef {
+                        line: self.current_line,
+                        column: self.token_column_marker,
+                    },
+                });
+            }
+            return Ok(None);
+        }
+
         // Fill buffers and bail out if necessary
         if let BailOut = self.fill_buffer(&current_char, peek) {
             return Ok(None);
@@ -144,8 +239,8 @@ impl LexBuffer {
         // If something is ready, pop new token out
         match self.mode {
 
-            // This just has to be here, fill_buffer already BailsOut in LineComment mode
-            LexingState::LineComment => {
+            // This just has to be here, fill_buffer already BailsOut in comment modes
+            LexingState::LineComment | LexingState::BlockComment => {
                 Ok(None)
             }
 
@@ -170,8 +265,29 @@ impl LexBuffer {
             }
 
             LexingState::Float => {
+                // Integer followed by range operator (1..2) is not a float after all.
+                // Integer is popped out and the first dot is left to buffer.
+                if current_char == '.' && char_is(peek, '.') {
+                    self.buffer.pop();
+                    let value: Result<i32, _> = self.buffer.parse();
+                    return match value {
+                        Ok(value) => {
+                            let token = self.pop_buffer(TokenKind::Integer(value));
+                            self.buffer.push(current_char);
+                            self.token_column_marker = self.current_column - 1;
+                            Ok(Some(token))
+                        }
+                        Err(_) => Err(LexingError {
+                            msg: "identifier can't start with digit".to_string(),
+                            location: SourceRef {
+                                line: self.current_line,
+                                column: self.token_column_marker,
+                            },
+                        })
+                    };
+                }
                 if is_delimiting_opt(peek) {
-                    let value: Result<f32, _> = self.buffer.parse();
+                    let value: Result<f64, _> = self.buffer.parse();
                     return match value {
                         Ok(value) => {
                             Ok(Some(self.pop_buffer(TokenKind::Float(value))))
@@ -190,7 +306,34 @@ impl LexBuffer {
 
             LexingState::String => {
                 if current_char == '"' {
-                    return Ok(Some(self.pop_buffer(TokenKind::Str(self.buffer.to_string()))));
+                    if self.interpolation.parts.is_empty() {
+                        return Ok(Some(self.pop_buffer(TokenKind::Str(self.buffer.to_string()))));
+                    }
+                    if !self.buffer.is_empty() {
+                        self.interpolation.parts.push(StrPart::Literal(self.buffer.to_string()));
+                    }
+                    let parts = std::mem::take(&mut self.interpolation.parts);
+                    return Ok(Some(self.pop_buffer(TokenKind::InterpolatedStr(parts))));
+                }
+                if peek.is_none() {
+                    return Err(LexingError {
+                        msg: "string is not terminated".to_string(),
+                        location: SourceRef {
+                            line: self.current_line,
+                            column: self.token_column_marker,
+                        },
+                    });
+                }
+                Ok(None)
+            }
+
+            LexingState::Interpolation => {
+                // Closing brace of the expression, lex the expression and continue the string
+                if self.interpolation.depth == 0 {
+                    let tokens = self.lex_interpolation()?;
+                    self.interpolation.parts.push(StrPart::Expression(tokens));
+                    self.buffer.clear();
+                    self.mode = LexingState::String;
                 }
                 if peek.is_none() {
                     return Err(LexingError {
@@ -227,6 +370,45 @@ impl LexBuffer {
                     "else" => Ok(self.pop_buffer_cond(
                         TokenKind::Else,
                         is_delimiting_opt(peek))),
+                    "for" => Ok(self.pop_buffer_cond(
+                        TokenKind::For,
+                        is_delimiting_opt(peek))),
+                    "in" => Ok(self.pop_buffer_cond(
+                        TokenKind::In,
+                        is_delimiting_opt(peek))),
+                    "while" => Ok(self.pop_buffer_cond(
+                        TokenKind::While,
+                        is_delimiting_opt(peek))),
+                    "break" => Ok(self.pop_buffer_cond(
+                        TokenKind::Break,
+                        is_delimiting_opt(peek))),
+                    "continue" => Ok(self.pop_buffer_cond(
+                        TokenKind::Continue,
+                        is_delimiting_opt(peek))),
+                    "match" => Ok(self.pop_buffer_cond(
+                        TokenKind::Match,
+                        is_delimiting_opt(peek))),
+                    "struct" => Ok(self.pop_buffer_cond(
+                        TokenKind::Struct,
+                        is_delimiting_opt(peek))),
+                    "import" => Ok(self.pop_buffer_cond(
+                        TokenKind::Import,
+                        is_delimiting_opt(peek))),
+                    "as" => Ok(self.pop_buffer_cond(
+                        TokenKind::As,
+                        is_delimiting_opt(peek))),
+                    "throw" => Ok(self.pop_buffer_cond(
+                        TokenKind::Throw,
+                        is_delimiting_opt(peek))),
+                    "try" => Ok(self.pop_buffer_cond(
+                        TokenKind::Try,
+                        is_delimiting_opt(peek))),
+                    "catch" => Ok(self.pop_buffer_cond(
+                        TokenKind::Catch,
+                        is_delimiting_opt(peek))),
+                    "finally" => Ok(self.pop_buffer_cond(
+                        TokenKind::Finally,
+                        is_delimiting_opt(peek))),
                     "null" => Ok(self.pop_buffer_cond(
                         TokenKind::Null,
                         is_delimiting_opt(peek))),
@@ -240,24 +422,57 @@ impl LexBuffer {
                     ")" => Ok(Some(self.pop_buffer(TokenKind::RightParens))),
                     "{" => Ok(Some(self.pop_buffer(TokenKind::LeftBrace))),
                     "}" => Ok(Some(self.pop_buffer(TokenKind::RightBrace))),
+                    "[" => Ok(Some(self.pop_buffer(TokenKind::LeftBracket))),
+                    "]" => Ok(Some(self.pop_buffer(TokenKind::RightBracket))),
                     "," => Ok(Some(self.pop_buffer(TokenKind::Comma))),
-                    "." => Ok(Some(self.pop_buffer(TokenKind::Dot))),
+                    "." => Ok(self.pop_buffer_cond(
+                        TokenKind::Dot,
+                        char_is_not(peek, '.'))),
+                    ".." => Ok(self.pop_buffer_cond(
+                        TokenKind::DotDot,
+                        char_is_not(peek, '=') && char_is_not(peek, '.'))),
+                    "..=" => Ok(Some(self.pop_buffer(TokenKind::DotDotEquals))),
+                    "..." => Ok(Some(self.pop_buffer(TokenKind::Ellipsis))),
+                    ":" => Ok(Some(self.pop_buffer(TokenKind::Colon))),
                     "-" => Ok(self.pop_buffer_cond(
                         TokenKind::Minus,
-                        char_is_not(peek, '>'))),
-                    "+" => Ok(Some(self.pop_buffer(TokenKind::Plus))),
-                    "/" => Ok(Some(self.pop_buffer(TokenKind::Division))),
-                    "*" => Ok(Some(self.pop_buffer(TokenKind::Multiplication))),
+                        char_is_not(peek, '>') && char_is_not(peek, '='))),
+                    "-=" => Ok(Some(self.pop_buffer(TokenKind::MinusAssign))),
+                    "+" => Ok(self.pop_buffer_cond(
+                        TokenKind::Plus,
+                        char_is_not(peek, '='))),
+                    "+=" => Ok(Some(self.pop_buffer(TokenKind::PlusAssign))),
+                    "/" => Ok(self.pop_buffer_cond(
+                        TokenKind::Division,
+                        char_is_not(peek, '='))),
+                    "/=" => Ok(Some(self.pop_buffer(TokenKind::DivisionAssign))),
+                    "*" => Ok(self.pop_buffer_cond(
+                        TokenKind::Multiplication,
+                        char_is_not(peek, '='))),
+                    "*=" => Ok(Some(self.pop_buffer(TokenKind::MultiplicationAssign))),
+                    "%" => Ok(Some(self.pop_buffer(TokenKind::Modulo))),
                     "->" => Ok(Some(self.pop_buffer(TokenKind::Arrow))),
                     ";" => Ok(Some(self.pop_buffer(TokenKind::Semicolon))),
                     "!" => Ok(self.pop_buffer_cond(
-                        TokenKind::Assign, // TODO: This should yield negate operator!
+                        TokenKind::Bang,
                         char_is_not(peek, '='))),
                     "!=" => Ok(Some(self.pop_buffer(TokenKind::NotEquals))),
                     "=" => Ok(self.pop_buffer_cond(
                         TokenKind::Assign,
                         char_is_not(peek, '='))),
                     "==" => Ok(Some(self.pop_buffer(TokenKind::Equals))),
+                    "<" => Ok(self.pop_buffer_cond(
+                        TokenKind::LessThan,
+                        char_is_not(peek, '='))),
+                    "<=" => Ok(Some(self.pop_buffer(TokenKind::LessOrEquals))),
+                    ">" => Ok(self.pop_buffer_cond(
+                        TokenKind::GreaterThan,
+                        char_is_not(peek, '='))),
+                    ">=" => Ok(Some(self.pop_buffer(TokenKind::GreaterOrEquals))),
+                    "&" => self.expect_doubled('&', peek),
+                    "&&" => Ok(Some(self.pop_buffer(TokenKind::And))),
+                    "|" => self.expect_doubled('|', peek),
+                    "||" => Ok(Some(self.pop_buffer(TokenKind::Or))),
 
                     // Match identifiers
 
@@ -274,6 +489,94 @@ impl LexBuffer {
         }
     }
 
+    /// Pushes character of escape sequence, escaped character is pushed to buffer
+    /// when the sequence is complete
+    fn push_escaped(&mut self, current_char: char) -> Result<(), LexingError> {
+        match std::mem::replace(&mut self.string_escape, StringEscape::None) {
+            StringEscape::None => (),
+            StringEscape::Started(column) => {
+                let escaped = match current_char {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    '\\' => '\\',
+                    '"' => '"',
+                    '$' => '$',
+                    'u' => {
+                        self.string_escape = StringEscape::Unicode(column, None);
+                        return Ok(());
+                    }
+                    _ => return Err(self.escape_error(
+                        format!("unknown escape sequence \\{}", current_char),
+                        column)),
+                };
+                self.buffer.push(escaped);
+            }
+            StringEscape::Unicode(column, None) => match current_char {
+                '{' => self.string_escape = StringEscape::Unicode(column, Some(String::new())),
+                _ => return Err(self.escape_error(
+                    "unicode escape must be written as \\u{XXXX}".to_string(),
+                    column)),
+            },
+            StringEscape::Unicode(column, Some(code)) => match current_char {
+                '}' => {
+                    let escaped = u32::from_str_radix(&code, 16).ok().and_then(std::char::from_u32);
+                    match escaped {
+                        Some(escaped) => self.buffer.push(escaped),
+                        None => return Err(self.escape_error(
+                            format!("invalid unicode escape \\u{{{}}}", code),
+                            column)),
+                    }
+                }
+                _ if current_char.is_ascii_hexdigit() && code.len() < 6 => {
+                    self.string_escape = StringEscape::Unicode(column, Some(format!("{}{}", code, current_char)));
+                }
+                _ => return Err(self.escape_error(
+                    format!("invalid unicode escape \\u{{{}{}", code, current_char),
+                    column)),
+            },
+        }
+        Ok(())
+    }
+
+    fn escape_error(&self, msg: String, column: u32) -> LexingError {
+        LexingError {
+            msg,
+            location: SourceRef {
+                line: self.current_line,
+                column,
+            },
+        }
+    }
+
+    /// Lexes the expression of `${...}` in buffer with a fresh lexer.
+    /// Source references are offset to point to the original source.
+    fn lex_interpolation(&self) -> Result<Vec<Token>, LexingError> {
+        let line_offset = self.interpolation.line - 1;
+        let column_offset = |line: u32| if line == 1 { self.interpolation.column } else { 0 };
+        match Lexer::new(&self.buffer) {
+            Ok(lexer) if lexer.tokens.is_empty() => Err(LexingError {
+                msg: "empty interpolation".to_string(),
+                location: SourceRef {
+                    line: self.interpolation.line,
+                    column: self.interpolation.column,
+                },
+            }),
+            Ok(lexer) => Ok(lexer.tokens.into_iter().map(|token| Token::new(
+                token.token_kind,
+                token.source_ref.line + line_offset,
+                token.source_ref.column + column_offset(token.source_ref.line))).collect()),
+            Err(error) => Err(LexingError {
+                msg: error.msg,
+                location: SourceRef {
+                    line: error.location.line + line_offset,
+                    column: error.location.column + column_offset(error.location.line),
+                },
+            }),
+        }
+    }
+
     /// Increments the counters and resets LineComment mode if newline is encountered
     /// Returns BailOut, if current_char was newline and thus OK(None) should be returned
     fn proceed_with_counters(&mut self, current_char: &char) -> ShouldContinue {
@@ -281,8 +584,16 @@ impl LexBuffer {
         match current_char {
             '\n' => {
                 if self.mode == LexingState::LineComment {
+                    self.end_line_comment();
                     self.mode = LexingState::Normal
                 }
+                if self.mode == LexingState::BlockComment {
+                    self.block_comment.previous = None;
+                }
+                // Expression lexer needs the newlines to keep lines and tokens apart
+                if self.mode == LexingState::Interpolation {
+                    self.buffer.push('\n')
+                }
                 self.current_line += 1;
                 self.current_column = 0;
                 self.token_column_marker = 0;
@@ -298,8 +609,31 @@ impl LexBuffer {
     fn fill_buffer(&mut self, current_char: &char, peek: Option<&char>) -> ShouldContinue {
         match self.mode {
 
-            // In LineComment mode, just BailOut always
-            LexingState::LineComment => BailOut,
+            // In LineComment mode, collect the comment for doc comments and BailOut always
+            LexingState::LineComment => {
+                self.buffer.push(*current_char);
+                BailOut
+            }
+
+            // In BlockComment mode, track nesting and BailOut always
+            LexingState::BlockComment => {
+                match (self.block_comment.previous, current_char) {
+                    (Some('/'), '*') => {
+                        self.block_comment.depth += 1;
+                        self.block_comment.previous = None;
+                    }
+                    (Some('*'), '/') => {
+                        self.block_comment.depth -= 1;
+                        self.block_comment.previous = None;
+                        if self.block_comment.depth == 0 {
+                            self.mode = LexingState::Normal;
+                            self.token_column_marker = self.current_column;
+                        }
+                    }
+                    _ => self.block_comment.previous = Some(*current_char),
+                }
+                BailOut
+            }
 
             // In Normal mode
             LexingState::Normal => match current_char {
@@ -313,9 +647,20 @@ impl LexBuffer {
                     self.mode = LexingState::LineComment;
                     BailOut
                 }
+                // New block comment starts from this character, the * opens depth 1
+                '/' if char_is(peek, '*') => {
+                    self.mode = LexingState::BlockComment;
+                    self.block_comment = BlockCommentState {
+                        depth: 0,
+                        previous: Some('/'),
+                        line: self.current_line,
+                        column: self.current_column - 1,
+                    };
+                    BailOut
+                }
                 // New integer starts when buffer is empty and current_character is digit
                 // Needs to Continue, because it can be an integer with just one digit
-                _ if self.buffer.is_empty() && current_char.is_digit(10) => {
+                _ if self.buffer.is_empty() && current_char.is_ascii_digit() => {
                     self.mode = LexingState::Integer;
                     self.buffer.push(*current_char);
                     // Handle the situation where float has one digit and thus
@@ -341,17 +686,20 @@ impl LexBuffer {
 
             // In String mode
             LexingState::String => match current_char {
-                // Escape flag encountered in string, set flag on and BailOut
-                _ if self.string_escape_flag => {
-                    // Escape flag was on, push this to buffer and BailOut because we're
-                    // in between on String
-                    self.buffer.push(*current_char);
-                    self.string_escape_flag = false;
-                    BailOut
+                // Escape sequence starts, following characters are handled in push_escaped.
+                // Continue is required because string can't end to escape (EOF)
+                '\\' => {
+                    self.string_escape = StringEscape::Started(self.current_column - 1);
+                    Continue
                 }
-                // Escape flag is set encountered, set flag on and BailOut
-                '\\' if !self.string_escape_flag => {
-                    self.string_escape_flag = true;
+                // Interpolated expression starts, the literal so far is a part of its own
+                '$' if char_is(peek, '{') => {
+                    if !self.buffer.is_empty() {
+                        self.interpolation.parts.push(StrPart::Literal(self.buffer.to_string()));
+                        self.buffer.clear();
+                    }
+                    self.mode = LexingState::Interpolation;
+                    self.interpolation.depth = 0;
                     BailOut
                 }
                 // In String mode when " is encountered, just Continue and new String will
@@ -366,6 +714,53 @@ impl LexBuffer {
                 }
             },
 
+            // In Interpolation mode collect the expression source until the matching }
+            LexingState::Interpolation => match current_char {
+                // Opening brace of ${, expression starts from the next column
+                '{' if self.interpolation.depth == 0 => {
+                    self.interpolation.depth = 1;
+                    self.interpolation.in_string = false;
+                    self.interpolation.escape_flag = false;
+                    self.interpolation.line = self.current_line;
+                    self.interpolation.column = self.current_column;
+                    BailOut
+                }
+                _ if self.interpolation.escape_flag => {
+                    self.interpolation.escape_flag = false;
+                    self.buffer.push(*current_char);
+                    Continue
+                }
+                '\\' if self.interpolation.in_string => {
+                    self.interpolation.escape_flag = true;
+                    self.buffer.push(*current_char);
+                    Continue
+                }
+                '"' => {
+                    self.interpolation.in_string = !self.interpolation.in_string;
+                    self.buffer.push(*current_char);
+                    Continue
+                }
+                '{' if !self.interpolation.in_string => {
+                    self.interpolation.depth += 1;
+                    self.buffer.push(*current_char);
+                    Continue
+                }
+                // Matching } is not part of the expression
+                '}' if !self.interpolation.in_string && self.interpolation.depth == 1 => {
+                    self.interpolation.depth = 0;
+                    Continue
+                }
+                '}' if !self.interpolation.in_string => {
+                    self.interpolation.depth -= 1;
+                    self.buffer.push(*current_char);
+                    Continue
+                }
+                _ => {
+                    self.buffer.push(*current_char);
+                    Continue
+                }
+            },
+
             // In Integer mode just push to buffer always
             // Continue will check if the Integer is malformed
             LexingState::Integer => {
@@ -379,15 +774,26 @@ impl LexBuffer {
             }
 
             // In Float mode just push to buffer always
-            LexingState::Float => match current_char {
-                _ => {
-                    self.buffer.push(*current_char);
-                    Continue
-                }
+            LexingState::Float => {
+                self.buffer.push(*current_char);
+                Continue
             }
         }
     }
 
+    /// Line comment ends, `/// text` is collected as doc comment for the next token.
+    /// Buffer has the comment after the first /.
+    fn end_line_comment(&mut self) {
+        if self.buffer.starts_with("//") && !self.buffer.starts_with("///") {
+            let line = self.buffer[2..].strip_prefix(' ').unwrap_or(&self.buffer[2..]).to_string();
+            self.doc = match self.doc.take() {
+                Some(doc) => Some(format!("{}\n{}", doc, line)),
+                None => Some(line),
+            };
+        }
+        self.buffer.clear();
+    }
+
     /// Pops Token out of buffer with given kind and resets
     /// column markers and mode back to Normal
     fn pop_buffer(&mut self, kind: TokenKind) -> Token {
@@ -397,9 +803,10 @@ impl LexBuffer {
         self.buffer.clear();
 
         // Create token with token_column_marker (the column that started token)
-        let new_token = Token::new(kind,
+        let mut new_token = Token::new(kind,
                                      self.current_line,
                                      self.token_column_marker);
+        new_token.doc = self.doc.take();
 
         // Set next token column marker to current_column
         self.token_column_marker = self.current_column;
@@ -407,6 +814,20 @@ impl LexBuffer {
         new_token
     }
 
+    /// Single & or | is not an operator, it must be followed by another one (&& or ||)
+    fn expect_doubled(&self, this: char, peek: Option<&char>) -> Result<Option<Token>, LexingError> {
+        if char_is(peek, this) {
+            return Ok(None);
+        }
+        Err(LexingError {
+            msg: format!("expecting {}{} but found single {}", this, this, this),
+            location: SourceRef {
+                line: self.current_line,
+                column: self.token_column_marker,
+            },
+        })
+    }
+
     /// Pops buffer to given kind if should_pop is true
     fn pop_buffer_cond(
         &mut self,
@@ -430,14 +851,22 @@ fn is_delimiting(c: &char) -> bool {
         ')' => true,
         '{' => true,
         '}' => true,
+        '[' => true,
+        ']' => true,
         '+' => true,
         '-' => true,
         '*' => true,
         '/' => true,
+        '%' => true,
         ',' => true,
         '.' => true,
+        ':' => true,
         '=' => true,
         '!' => true,
+        '&' => true,
+        '|' => true,
+        '<' => true,
+        '>' => true,
         _ => false
     }
 }
@@ -466,7 +895,7 @@ fn char_is_not(peek: Option<&char>, this: char) -> bool {
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
-    use crate::lexer::tokens::TokenKind::{Let, Identifier, Assign, Integer, Str, Semicolon, RightParens, LeftParens, Arrow, Minus, Plus, Fun, Comma, Division, Equals, Const, Float, Dot, Multiplication, Null, LeftBrace, RightBrace, Return, If, Else, True, False, NotEquals};
+    use crate::lexer::tokens::TokenKind::{Let, Identifier, Assign, Integer, Str, Semicolon, RightParens, LeftParens, Arrow, Minus, Plus, Fun, Comma, Division, Equals, Const, Float, Dot, Multiplication, Modulo, Bang, And, Or, Null, LeftBrace, RightBrace, Return, If, Else, True, False, NotEquals, LessThan, GreaterThan, LessOrEquals, GreaterOrEquals, While, Break, Continue, Colon, For, In, DotDot, DotDotEquals, LeftBracket, RightBracket, InterpolatedStr, Match, Struct, Import, As, Throw, Try, Catch, Finally, Ellipsis, PlusAssign, MinusAssign, MultiplicationAssign, DivisionAssign};
 
     // Internal implementation test helpers
 
@@ -503,7 +932,10 @@ mod tests {
             current_line: 0,
             current_column: 0,
             token_column_marker: 0,
-            string_escape_flag: false,
+            string_escape: StringEscape::None,
+            interpolation: InterpolationState::default(),
+            block_comment: BlockCommentState::default(),
+            doc: None,
         })
     }
 
@@ -546,9 +978,29 @@ mod tests {
         token_lexes_to("return", Return);
         token_lexes_to("if", If);
         token_lexes_to("else", Else);
+        token_lexes_to("while", While);
+        token_lexes_to("for", For);
+        token_lexes_to("match", Match);
+        token_lexes_to("struct", Struct);
+        token_lexes_to("import", Import);
+        token_lexes_to("as", As);
+        token_lexes_to("throw", Throw);
+        token_lexes_to("try", Try);
+        token_lexes_to("catch", Catch);
+        token_lexes_to("finally", Finally);
+        token_lexes_to("in", In);
+        token_lexes_to("break", Break);
+        token_lexes_to("continue", Continue);
         token_lexes_to("=", Assign);
         token_lexes_to("==", Equals);
         token_lexes_to("!=", NotEquals);
+        token_lexes_to("!", Bang);
+        token_lexes_to("<", LessThan);
+        token_lexes_to(">", GreaterThan);
+        token_lexes_to("<=", LessOrEquals);
+        token_lexes_to(">=", GreaterOrEquals);
+        token_lexes_to("&&", And);
+        token_lexes_to("||", Or);
         token_lexes_to("foo", Identifier("foo".to_string()));
         for nmbr in 0..100 {
             token_lexes_to(&nmbr.to_string(), Integer(nmbr));
@@ -562,13 +1014,24 @@ mod tests {
         token_lexes_to(")", RightParens);
         token_lexes_to("{", LeftBrace);
         token_lexes_to("}", RightBrace);
+        token_lexes_to("[", LeftBracket);
+        token_lexes_to("]", RightBracket);
         token_lexes_to("->", Arrow);
         token_lexes_to("-", Minus);
         token_lexes_to("+", Plus);
         token_lexes_to("/", Division);
         token_lexes_to("*", Multiplication);
+        token_lexes_to("%", Modulo);
         token_lexes_to(",", Comma);
         token_lexes_to(".", Dot);
+        token_lexes_to(":", Colon);
+        token_lexes_to("..", DotDot);
+        token_lexes_to("..=", DotDotEquals);
+        token_lexes_to("...", Ellipsis);
+        token_lexes_to("+=", PlusAssign);
+        token_lexes_to("-=", MinusAssign);
+        token_lexes_to("*=", MultiplicationAssign);
+        token_lexes_to("/=", DivisionAssign);
         token_lexes_to("null", Null);
         token_lexes_to("true", True);
         token_lexes_to("false", False);
@@ -662,6 +1125,21 @@ mod tests {
             dummy_token(Division),
             dummy_token(Integer(2)),
         ]);
+        with_input_lexes_to("1/2", vec![
+            dummy_token(Integer(1)),
+            dummy_token(Division),
+            dummy_token(Integer(2)),
+        ]);
+        with_input_lexes_to("1 % 2", vec![
+            dummy_token(Integer(1)),
+            dummy_token(Modulo),
+            dummy_token(Integer(2)),
+        ]);
+        with_input_lexes_to("1%2", vec![
+            dummy_token(Integer(1)),
+            dummy_token(Modulo),
+            dummy_token(Integer(2)),
+        ]);
         with_input_lexes_to("1 * 2", vec![
             dummy_token(Integer(1)),
             dummy_token(Multiplication),
@@ -682,6 +1160,110 @@ mod tests {
             dummy_token(Equals),
             dummy_token(Integer(2)),
         ]);
+        with_input_lexes_to("a<b", vec![
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(LessThan),
+            dummy_token(Identifier("b".to_string())),
+        ]);
+        with_input_lexes_to("a>=b", vec![
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(GreaterOrEquals),
+            dummy_token(Identifier("b".to_string())),
+        ]);
+        with_input_lexes_to("fun (a)->a>1", vec![
+            dummy_token(Fun),
+            dummy_token(LeftParens),
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(RightParens),
+            dummy_token(Arrow),
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(GreaterThan),
+            dummy_token(Integer(1)),
+        ]);
+        with_input_lexes_to("outer: while a { break outer; }", vec![
+            dummy_token(Identifier("outer".to_string())),
+            dummy_token(Colon),
+            dummy_token(While),
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(LeftBrace),
+            dummy_token(Break),
+            dummy_token(Identifier("outer".to_string())),
+            dummy_token(Semicolon),
+            dummy_token(RightBrace),
+        ]);
+        with_input_lexes_to("for x in 0..10", vec![
+            dummy_token(For),
+            dummy_token(Identifier("x".to_string())),
+            dummy_token(In),
+            dummy_token(Integer(0)),
+            dummy_token(DotDot),
+            dummy_token(Integer(10)),
+        ]);
+        with_input_lexes_to("10..=20", vec![
+            dummy_token(Integer(10)),
+            dummy_token(DotDotEquals),
+            dummy_token(Integer(20)),
+        ]);
+        with_input_lexes_to("a..b", vec![
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(DotDot),
+            dummy_token(Identifier("b".to_string())),
+        ]);
+        with_input_lexes_to("a+=1;b-=-1;c*=d/=2", vec![
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(PlusAssign),
+            dummy_token(Integer(1)),
+            dummy_token(Semicolon),
+            dummy_token(Identifier("b".to_string())),
+            dummy_token(MinusAssign),
+            dummy_token(Minus),
+            dummy_token(Integer(1)),
+            dummy_token(Semicolon),
+            dummy_token(Identifier("c".to_string())),
+            dummy_token(MultiplicationAssign),
+            dummy_token(Identifier("d".to_string())),
+            dummy_token(DivisionAssign),
+            dummy_token(Integer(2)),
+        ]);
+        with_input_lexes_to("f(a, ...xs)", vec![
+            dummy_token(Identifier("f".to_string())),
+            dummy_token(LeftParens),
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(Comma),
+            dummy_token(Ellipsis),
+            dummy_token(Identifier("xs".to_string())),
+            dummy_token(RightParens),
+        ]);
+        with_input_lexes_to("1.5..2", vec![
+            dummy_token(Float(1.5)),
+            dummy_token(DotDot),
+            dummy_token(Integer(2)),
+        ]);
+        with_input_lexes_to("xs[1]=[a]", vec![
+            dummy_token(Identifier("xs".to_string())),
+            dummy_token(LeftBracket),
+            dummy_token(Integer(1)),
+            dummy_token(RightBracket),
+            dummy_token(Assign),
+            dummy_token(LeftBracket),
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(RightBracket),
+        ]);
+        with_input_lexes_to("inside", vec![
+            dummy_token(Identifier("inside".to_string())),
+        ]);
+        with_input_lexes_to("!a", vec![
+            dummy_token(Bang),
+            dummy_token(Identifier("a".to_string())),
+        ]);
+        with_input_lexes_to("a&&!b||c", vec![
+            dummy_token(Identifier("a".to_string())),
+            dummy_token(And),
+            dummy_token(Bang),
+            dummy_token(Identifier("b".to_string())),
+            dummy_token(Or),
+            dummy_token(Identifier("c".to_string())),
+        ]);
         with_input_lexes_to("null + null", vec![
             dummy_token(Null),
             dummy_token(Plus),
@@ -729,6 +1311,17 @@ mod tests {
                 token_at(Str("bar value with whitespace".to_string()), 2, 10),
                 token_at(Semicolon, 2, 37),
             ],
+        );
+        with_input_lexes_to_assert_columns(
+            "10..=20 1..2",
+            vec![
+                token_at(Integer(10), 1, 0),
+                token_at(DotDotEquals, 1, 2),
+                token_at(Integer(20), 1, 5),
+                token_at(Integer(1), 1, 8),
+                token_at(DotDot, 1, 9),
+                token_at(Integer(2), 1, 11),
+            ],
         )
     }
 
@@ -741,6 +1334,20 @@ mod tests {
                 column: 3,
             },
         });
+        with_input_errors_to("a & b", LexingError {
+            msg: "expecting && but found single &".to_string(),
+            location: SourceRef {
+                line: 1,
+                column: 2,
+            },
+        });
+        with_input_errors_to("a | b", LexingError {
+            msg: "expecting || but found single |".to_string(),
+            location: SourceRef {
+                line: 1,
+                column: 2,
+            },
+        });
         with_input_errors_to("\"hello", LexingError {
             msg: "string is not terminated".to_string(),
             location: SourceRef {
@@ -854,6 +1461,60 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_block_comments() {
+        with_input_lexes_to("/* Hello world */", vec![]);
+        with_input_lexes_to("1 /* a /* b */ c */ 2", vec![
+            dummy_token(Integer(1)),
+            dummy_token(Integer(2)),
+        ]);
+        with_input_lexes_to("1/**/2 /* multi\nline */", vec![
+            dummy_token(Integer(1)),
+            dummy_token(Integer(2)),
+        ]);
+        with_input_lexes_to_assert_columns("a /* x */ b", vec![
+            token_at(Identifier("a".to_string()), 1, 0),
+            token_at(Identifier("b".to_string()), 1, 10),
+        ]);
+        with_input_errors_at("1 /* abc", LexingError {
+            msg: "block comment is not terminated".to_string(),
+            location: SourceRef { line: 1, column: 2 },
+        });
+        with_input_errors_at("1\n /* a /* b */", LexingError {
+            msg: "block comment is not terminated".to_string(),
+            location: SourceRef { line: 2, column: 1 },
+        });
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        with_input_documents("/// Adds\n///numbers\nfun add(a, b) -> a + b", vec![
+            Some("Adds\nnumbers".to_string()), None, None,
+        ]);
+        with_input_documents("//// not a doc\n// nor this\nlet a = 1", vec![
+            None, None, None, None,
+        ]);
+        // Doc is attached only to the next token
+        with_input_documents("/// a\nlet a = 1; /* b */ /// c\nlet", vec![
+            Some("a".to_string()), None, None, None, None, Some("c".to_string()),
+        ]);
+    }
+
+    /// Asserts doc comments attached to the first tokens of the input
+    fn with_input_documents(input: &str, expected_docs: Vec<Option<String>>) {
+        match Lexer::new(input) {
+            Ok(mut lexer) => {
+                for expected_doc in expected_docs {
+                    match lexer.next() {
+                        None => panic!("Expecting token but None returned!"),
+                        Some(token) => assert_eq!(token.doc, expected_doc, "Wrong doc in `{}`", input),
+                    }
+                }
+            }
+            Err(error) => panic!("Unexpected lexing error: {}", error.msg)
+        }
+    }
+
     fn token_lexes_to(input: &str, expected_kind: TokenKind) {
         with_input_lexes_to(input, vec![dummy_token(expected_kind)]);
     }
@@ -862,6 +1523,126 @@ mod tests {
         do_lexing_assertion(input, expected_tokens, false)
     }
 
+    #[test]
+    fn test_string_interpolation() {
+        with_input_lexes_to_assert_columns(
+            "\"a${b + 1}c\" \"${d}\"",
+            vec![
+                token_at(InterpolatedStr(vec![
+                    StrPart::Literal("a".to_string()),
+                    StrPart::Expression(vec![
+                        token_at(Identifier("b".to_string()), 1, 4),
+                        token_at(Plus, 1, 6),
+                        token_at(Integer(1), 1, 8),
+                    ]),
+                    StrPart::Literal("c".to_string()),
+                ]), 1, 0),
+                token_at(InterpolatedStr(vec![
+                    StrPart::Expression(vec![
+                        token_at(Identifier("d".to_string()), 1, 16),
+                    ]),
+                ]), 1, 13),
+            ],
+        );
+        // Nested tokens are compared with their source references
+        with_input_lexes_to(
+            "\"${\n  x }\"",
+            vec![
+                dummy_token(InterpolatedStr(vec![
+                    StrPart::Expression(vec![
+                        token_at(Identifier("x".to_string()), 2, 2),
+                    ]),
+                ])),
+            ],
+        );
+        // Strings and braces inside the expression
+        with_input_lexes_to(
+            "\"${{ \"}\" }}\"",
+            vec![
+                dummy_token(InterpolatedStr(vec![
+                    StrPart::Expression(vec![
+                        token_at(LeftBrace, 1, 3),
+                        token_at(Str("}".to_string()), 1, 5),
+                        token_at(RightBrace, 1, 9),
+                    ]),
+                ])),
+            ],
+        );
+        // Escaped $ is just a character
+        with_input_lexes_to("\"\\${a}\"", vec![
+            dummy_token(Str("${a}".to_string())),
+        ]);
+        with_input_lexes_to("\"$a\"", vec![
+            dummy_token(Str("$a".to_string())),
+        ]);
+        with_input_errors_to("\"${a\"", LexingError {
+            msg: "string is not terminated".to_string(),
+            location: SourceRef { line: 1, column: 0 },
+        });
+        with_input_errors_to("\"${a}", LexingError {
+            msg: "string is not terminated".to_string(),
+            location: SourceRef { line: 1, column: 0 },
+        });
+        with_input_errors_to("\"${}\"", LexingError {
+            msg: "empty interpolation".to_string(),
+            location: SourceRef { line: 1, column: 3 },
+        });
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        with_input_lexes_to("\"a\\nb\\tc\\rd\\0e\"", vec![
+            dummy_token(Str("a\nb\tc\rd\0e".to_string())),
+        ]);
+        with_input_lexes_to("\"\\\\ \\\" \\$\"", vec![
+            dummy_token(Str("\\ \" $".to_string())),
+        ]);
+        with_input_lexes_to("\"\\u{41}\\u{e4}\\u{1F600}\"", vec![
+            dummy_token(Str("Aä😀".to_string())),
+        ]);
+        with_input_lexes_to("\"${\"\\u{41}\"}\\n\"", vec![
+            dummy_token(InterpolatedStr(vec![
+                StrPart::Expression(vec![
+                    token_at(Str("A".to_string()), 1, 3),
+                ]),
+                StrPart::Literal("\n".to_string()),
+            ])),
+        ]);
+        with_input_errors_at("let a = \"ab\\q\"", LexingError {
+            msg: "unknown escape sequence \\q".to_string(),
+            location: SourceRef { line: 1, column: 11 },
+        });
+        with_input_errors_at("1;\n  \"\\x\"", LexingError {
+            msg: "unknown escape sequence \\x".to_string(),
+            location: SourceRef { line: 2, column: 3 },
+        });
+        with_input_errors_at("\"\\u41\"", LexingError {
+            msg: "unicode escape must be written as \\u{XXXX}".to_string(),
+            location: SourceRef { line: 1, column: 1 },
+        });
+        with_input_errors_at("\"\\u{zz}\"", LexingError {
+            msg: "invalid unicode escape \\u{z".to_string(),
+            location: SourceRef { line: 1, column: 1 },
+        });
+        with_input_errors_at("\"\\u{D800}\"", LexingError {
+            msg: "invalid unicode escape \\u{D800}".to_string(),
+            location: SourceRef { line: 1, column: 1 },
+        });
+        with_input_errors_at("\"\\u{}\"", LexingError {
+            msg: "invalid unicode escape \\u{}".to_string(),
+            location: SourceRef { line: 1, column: 1 },
+        });
+        with_input_errors_to("\"abc\\", LexingError {
+            msg: "string is not terminated".to_string(),
+            location: SourceRef { line: 1, column: 0 },
+        });
+        // Nested string in interpolation reports location in original source
+        with_input_errors_at("\"${\"\\q\"}\"", LexingError {
+            msg: "unknown escape sequence \\q".to_string(),
+            location: SourceRef { line: 1, column: 4 },
+        });
+    }
+
     fn with_input_errors_to(input: &str, expected_error: LexingError) {
         match Lexer::new(input) {
             Ok(_) => panic!("Expecting lexer to error, but working lexer was returned. Input: {}", input),
@@ -871,6 +1652,17 @@ mod tests {
         }
     }
 
+    /// Like with_input_errors_to, but asserts the location of the error too
+    fn with_input_errors_at(input: &str, expected_error: LexingError) {
+        match Lexer::new(input) {
+            Ok(_) => panic!("Expecting lexer to error, but working lexer was returned. Input: {}", input),
+            Err(error) => {
+                assert_eq!(expected_error.msg, error.msg);
+                assert_eq!(expected_error.location, error.location, "Wrong location for input: {}", input);
+            }
+        }
+    }
+
     fn with_input_lexes_to_assert_columns(input: &str, expected_tokens: Vec<Token>) {
         do_lexing_assertion(input, expected_tokens, true)
     }
@@ -1,10 +1,12 @@
 use std::fmt::{Display, Formatter};
 use crate::parser::ParseError;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Token {
     pub source_ref: SourceRef,
     pub token_kind: TokenKind,
+    /// `///` doc comment lines preceding this token
+    pub doc: Option<String>,
 }
 
 impl Token {
@@ -16,6 +18,7 @@ impl Token {
                 line,
                 column,
             },
+            doc: None,
         }
     }
 
@@ -55,6 +58,15 @@ impl Token {
         }
     }
 
+    pub fn is_left_brace(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::LeftBrace => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting {{ but {} found", self)
+            })
+        }
+    }
+
     pub fn is_right_brace(&self) -> Result<(), ParseError> {
         match &self.token_kind {
             TokenKind::RightBrace => Ok(()),
@@ -64,6 +76,15 @@ impl Token {
         }
     }
 
+    pub fn is_right_bracket(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::RightBracket => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting ] but {} found", self)
+            })
+        }
+    }
+
     pub fn is_semicolon(&self) -> Result<(), ParseError> {
         match &self.token_kind {
             TokenKind::Semicolon => Ok(()),
@@ -82,6 +103,51 @@ impl Token {
         }
     }
 
+    pub fn is_else(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::Else => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting else but {} found", self)
+            })
+        }
+    }
+
+    pub fn is_colon(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::Colon => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting : but {} found", self)
+            })
+        }
+    }
+
+    pub fn is_in(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::In => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting in but {} found", self)
+            })
+        }
+    }
+
+    pub fn is_while(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::While => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting while but {} found", self)
+            })
+        }
+    }
+
+    pub fn is_for(&self) -> Result<(), ParseError> {
+        match &self.token_kind {
+            TokenKind::For => Ok(()),
+            _ => Err( ParseError {
+                msg: format!("Expecting for but {} found", self)
+            })
+        }
+    }
+
     pub fn is_comma(&self) -> Result<(), ParseError> {
         match &self.token_kind {
             TokenKind::Comma => Ok(()),
@@ -98,7 +164,7 @@ impl Display for Token {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct SourceRef {
     pub line: u32,
     pub column: u32,
@@ -110,7 +176,14 @@ impl Display for SourceRef {
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// Part of an interpolated string literal "a ${b} c"
+#[derive(Debug, PartialEq, Clone)]
+pub enum StrPart {
+    Literal(String),
+    Expression(Vec<Token>),
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum TokenKind {
     // Reserved words
     Let,
@@ -119,33 +192,65 @@ pub enum TokenKind {
     Return,
     If,
     Else,
+    While,
+    For,
+    In,
+    Break,
+    Continue,
+    Match,
+    Struct,
+    Import,
+    As,
+    Throw,
+    Try,
+    Catch,
+    Finally,
 
     // Operators
     Assign,
+    PlusAssign,
+    MinusAssign,
+    MultiplicationAssign,
+    DivisionAssign,
     Equals,
     NotEquals,
+    LessThan,
+    GreaterThan,
+    LessOrEquals,
+    GreaterOrEquals,
     Minus,
     Plus,
     Multiplication,
     Division,
+    Modulo,
+    Bang,
+    And,
+    Or,
     Arrow,
+    DotDot,
+    DotDotEquals,
+    Ellipsis,
 
     // Delimiters
     Comma,
     Dot,
+    Colon,
     Semicolon,
     LeftParens,
     RightParens,
     LeftBrace,
     RightBrace,
+    LeftBracket,
+    RightBracket,
 
     // Identifier
     Identifier(String),
 
     // Literals
     Integer(i32),
-    Float(f32),
+    Float(f64),
     Str(String),
+    InterpolatedStr(Vec<StrPart>),
     Null,
     True,
     False,
@@ -6,6 +6,7 @@ use crate::parser::Parser;
 use crate::ast::TypeMatcher;
 use crate::bogusstd::prepare_scope;
 use std::{env, fs};
+use std::path::Path;
 
 mod lexer;
 mod parser;
@@ -31,11 +32,14 @@ fn main() {
     let args: Vec<String> = env::args().collect();
     let arg_one = args.get(1).expect("Pass filename or repl as an argument");
 
-    let mut scope = Scope::new();
-    prepare_scope(&mut scope);
     if arg_one == "repl" {
+        let mut scope = Scope::new();
+        prepare_scope(&mut scope);
         run_repl(&mut scope)
     } else {
+        // Imports are resolved relative to the evaluated file
+        let mut scope = Scope::for_file(Path::new(arg_one));
+        prepare_scope(&mut scope);
         let contents = fs::read_to_string(arg_one)
             .expect("Something went wrong reading the file");
         eval(contents.as_str(), &mut scope)
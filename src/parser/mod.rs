@@ -5,40 +5,84 @@ use crate::ast::{Expression};
 use crate::parser::p_o_plus::PlusParselet;
 use crate::parser::p_v_identifier::IdentifierParselet;
 use crate::parser::p_v_integer::IntegerParselet;
+use crate::parser::p_v_float::FloatParselet;
 use crate::parser::p_o_minus::MinusParselet;
 use crate::parser::p_s_let::LetParselet;
 use crate::parser::p_d_semicolon::SemicolonParselet;
 use crate::parser::p_o_multiplication::MultiplicationParselet;
+use crate::parser::p_o_division::DivisionParselet;
+use crate::parser::p_o_modulo::ModuloParselet;
 use crate::parser::p_d_parens::{LeftParensParselet, RightParensParselet};
 use crate::parser::p_s_fun::FunParselet;
 use crate::parser::p_d_comma::CommaParselet;
+use crate::parser::p_d_misplaced::MisplacedParselet;
 use crate::parser::p_v_string::StringParselet;
+use crate::parser::p_v_interpolated::InterpolatedStringParselet;
 use crate::parser::p_v_null::NullParselet;
 use crate::parser::p_d_brace::{LeftBraceParselet, RightBraceParselet};
+use crate::parser::p_d_bracket::{LeftBracketParselet, RightBracketParselet};
+use crate::parser::p_d_dot::DotParselet;
 use crate::parser::p_s_return::ReturnParselet;
 use crate::parser::p_s_assign::AssignParselet;
+use crate::ast::s_assign::CompoundOperator;
 use crate::parser::p_v_boolean::BooleanParselet;
 use crate::parser::p_o_equals::{EqualsParselet, EqualsOrNequals};
+use crate::parser::p_o_comparison::ComparisonParselet;
+use crate::ast::e_comparison::ComparisonOperator;
 use crate::parser::p_s_if::IfParselet;
+use crate::parser::p_s_while::WhileParselet;
+use crate::parser::p_s_for::ForParselet;
+use crate::parser::p_o_range::RangeParselet;
+use crate::parser::p_s_break::{BreakParselet, ContinueParselet};
+use crate::parser::p_o_bang::BangParselet;
+use crate::parser::p_o_logical::{AndParselet, OrParselet};
+use crate::parser::p_s_const::ConstParselet;
+use crate::parser::p_s_match::MatchParselet;
+use crate::parser::p_s_struct::StructParselet;
+use crate::parser::p_s_import::ImportParselet;
+use crate::parser::p_s_throw::ThrowParselet;
+use crate::parser::p_s_try::TryParselet;
+use crate::ast::s_located::LocatedStatement;
+use crate::ast::s_grouped::GroupedStatement;
 
 mod p_o_plus;
 mod p_o_minus;
 mod p_o_multiplication;
+mod p_o_division;
+mod p_o_modulo;
 mod p_d_parens;
 mod p_v_identifier;
 mod p_v_integer;
+mod p_v_float;
 mod p_v_string;
+mod p_v_interpolated;
 mod p_s_let;
 mod p_d_semicolon;
 mod p_v_null;
 mod p_s_fun;
 mod p_d_comma;
+mod p_d_misplaced;
 mod p_d_brace;
+mod p_d_bracket;
+mod p_d_dot;
 mod p_s_return;
 mod p_s_assign;
 mod p_v_boolean;
 mod p_o_equals;
+mod p_o_comparison;
 mod p_s_if;
+mod p_s_while;
+mod p_s_for;
+mod p_o_range;
+mod p_s_break;
+mod p_o_bang;
+mod p_o_logical;
+mod p_s_const;
+mod p_s_match;
+mod p_s_struct;
+mod p_s_import;
+mod p_s_throw;
+mod p_s_try;
 
 pub struct ParseError {
     pub msg: String,
@@ -63,9 +107,9 @@ pub struct Parser<'a> {
 }
 
 impl Parser<'_> {
-    pub fn new(lexer: &mut Lexer) -> Parser {
+    pub fn new(lexer: &mut Lexer) -> Parser<'_> {
         Parser {
-            lexer: lexer
+            lexer
         }
     }
 
@@ -73,7 +117,7 @@ impl Parser<'_> {
         let mut output: Vec<Rc<dyn Expression>> = vec![];
 
         while self.lexer.has_next() {
-            output.push(parse_expression(0, &mut self.lexer)?)
+            output.push(parse_statement(0, self.lexer)?)
         }
 
         Ok(output)
@@ -81,54 +125,133 @@ impl Parser<'_> {
 }
 
 fn get_parselet(token: &Token) -> Box<dyn Parselet> {
-    return match &token.token_kind {
+    match &token.token_kind {
         TokenKind::Identifier(name) => Box::new(IdentifierParselet { value: name.clone() }),
         TokenKind::Integer(value) => Box::new(IntegerParselet { value: *value }),
+        TokenKind::Float(value) => Box::new(FloatParselet { value: *value }),
         TokenKind::Str(value) => Box::new(StringParselet { value: value.clone() }),
+        TokenKind::InterpolatedStr(parts) => Box::new(InterpolatedStringParselet { parts: parts.clone() }),
         TokenKind::True => Box::new(BooleanParselet { value: true }),
         TokenKind::False => Box::new(BooleanParselet { value: false }),
         TokenKind::Plus => Box::new(PlusParselet {}),
         TokenKind::Minus => Box::new(MinusParselet {}),
         TokenKind::Equals => Box::new(EqualsParselet { equality_type: EqualsOrNequals::Equals }),
         TokenKind::NotEquals => Box::new(EqualsParselet { equality_type: EqualsOrNequals::Nequals }),
+        TokenKind::LessThan => Box::new(ComparisonParselet { operator: ComparisonOperator::LessThan }),
+        TokenKind::GreaterThan => Box::new(ComparisonParselet { operator: ComparisonOperator::GreaterThan }),
+        TokenKind::LessOrEquals => Box::new(ComparisonParselet { operator: ComparisonOperator::LessOrEquals }),
+        TokenKind::GreaterOrEquals => Box::new(ComparisonParselet { operator: ComparisonOperator::GreaterOrEquals }),
+        TokenKind::Bang => Box::new(BangParselet {}),
+        TokenKind::And => Box::new(AndParselet {}),
+        TokenKind::Or => Box::new(OrParselet {}),
         TokenKind::Multiplication => Box::new(MultiplicationParselet {}),
+        TokenKind::Division => Box::new(DivisionParselet {}),
+        TokenKind::Modulo => Box::new(ModuloParselet {}),
         TokenKind::LeftParens => Box::new(LeftParensParselet {}),
         TokenKind::RightParens => Box::new(RightParensParselet {}),
         TokenKind::LeftBrace => Box::new(LeftBraceParselet {}),
         TokenKind::RightBrace => Box::new(RightBraceParselet {}),
+        TokenKind::LeftBracket => Box::new(LeftBracketParselet {}),
+        TokenKind::RightBracket => Box::new(RightBracketParselet {}),
+        TokenKind::Dot => Box::new(DotParselet {}),
         TokenKind::If => Box::new(IfParselet {}),
+        TokenKind::Else => Box::new(MisplacedParselet { token: "else" }),
+        TokenKind::While => Box::new(WhileParselet {}),
+        TokenKind::For => Box::new(ForParselet {}),
+        TokenKind::In => Box::new(MisplacedParselet { token: "in" }),
+        TokenKind::Match => Box::new(MatchParselet {}),
+        TokenKind::Arrow => Box::new(MisplacedParselet { token: "->" }),
+        TokenKind::Struct => Box::new(StructParselet {}),
+        TokenKind::Import => Box::new(ImportParselet {}),
+        TokenKind::As => Box::new(MisplacedParselet { token: "as" }),
+        TokenKind::Throw => Box::new(ThrowParselet {}),
+        TokenKind::Try => Box::new(TryParselet {}),
+        TokenKind::Catch => Box::new(MisplacedParselet { token: "catch" }),
+        TokenKind::Finally => Box::new(MisplacedParselet { token: "finally" }),
+        TokenKind::DotDot => Box::new(RangeParselet { inclusive: false }),
+        TokenKind::DotDotEquals => Box::new(RangeParselet { inclusive: true }),
+        TokenKind::Ellipsis => Box::new(MisplacedParselet { token: "..." }),
+        TokenKind::Break => Box::new(BreakParselet {}),
+        TokenKind::Continue => Box::new(ContinueParselet {}),
         TokenKind::Let => Box::new(LetParselet {}),
+        TokenKind::Const => Box::new(ConstParselet {}),
         TokenKind::Fun => Box::new(FunParselet {}),
         TokenKind::Return => Box::new(ReturnParselet {}),
         TokenKind::Semicolon => Box::new(SemicolonParselet {}),
         TokenKind::Comma => Box::new(CommaParselet {}),
+        TokenKind::Colon => Box::new(MisplacedParselet { token: ":" }),
         TokenKind::Null => Box::new(NullParselet {}),
-        TokenKind::Assign => Box::new(AssignParselet {}),
-        _ => { panic!("get_parselet() not implemented for {:?}", token.token_kind); }
-    };
+        TokenKind::Assign => Box::new(AssignParselet { operator: None }),
+        TokenKind::PlusAssign => Box::new(AssignParselet { operator: Some(CompoundOperator::Plus) }),
+        TokenKind::MinusAssign => Box::new(AssignParselet { operator: Some(CompoundOperator::Minus) }),
+        TokenKind::MultiplicationAssign => Box::new(AssignParselet { operator: Some(CompoundOperator::Multiplication) }),
+        TokenKind::DivisionAssign => Box::new(AssignParselet { operator: Some(CompoundOperator::Division) }),
+    }
 }
 
 fn rbp_for(token: Option<&Token>) -> u32 {
     if let Some(token) = token {
         return match token.token_kind {
             TokenKind::Identifier(_) => 0,
-            TokenKind::Assign => 1,
+            TokenKind::Assign => 2,
+            TokenKind::PlusAssign => 2,
+            TokenKind::MinusAssign => 2,
+            TokenKind::MultiplicationAssign => 2,
+            TokenKind::DivisionAssign => 2,
             TokenKind::Integer(_) => 0,
-            TokenKind::Plus => 5,
-            TokenKind::Minus => 5,
-            TokenKind::Multiplication => 10,
-            TokenKind::Equals => 30, // Dunno?
-            TokenKind::NotEquals => 30, // Dunno?
+            TokenKind::Float(_) => 0,
+            TokenKind::Str(_) => 0,
+            TokenKind::InterpolatedStr(_) => 0,
+            TokenKind::Plus => 10,
+            TokenKind::Minus => 10,
+            TokenKind::Multiplication => 20,
+            TokenKind::Division => 20,
+            TokenKind::Modulo => 20,
+            TokenKind::Equals => 6,
+            TokenKind::NotEquals => 6,
+            TokenKind::LessThan => 7,
+            TokenKind::GreaterThan => 7,
+            TokenKind::LessOrEquals => 7,
+            TokenKind::GreaterOrEquals => 7,
+            TokenKind::DotDot => 8,
+            TokenKind::DotDotEquals => 8,
+            TokenKind::Bang => 0,
+            TokenKind::And => 4,
+            TokenKind::Or => 3,
             TokenKind::LeftParens => 50,
             TokenKind::RightParens => 1,
             TokenKind::LeftBrace => 0,
             TokenKind::RightBrace => 1,
+            TokenKind::LeftBracket => 50,
+            TokenKind::RightBracket => 1,
+            TokenKind::Dot => 50,
             TokenKind::Let => 0,
+            TokenKind::Const => 0,
             TokenKind::Fun => 0,
             TokenKind::Return => 0,
+            TokenKind::If => 0,
+            TokenKind::Else => 0,
+            TokenKind::While => 0,
+            TokenKind::For => 0,
+            TokenKind::Match => 0,
+            TokenKind::Struct => 0,
+            TokenKind::Import => 0,
+            TokenKind::As => 0,
+            TokenKind::Throw => 0,
+            TokenKind::Try => 0,
+            TokenKind::Catch => 0,
+            TokenKind::Finally => 0,
+            TokenKind::Arrow => 0,
+            TokenKind::In => 0,
+            TokenKind::Break => 0,
+            TokenKind::Continue => 0,
+            TokenKind::Colon => 0,
+            TokenKind::Ellipsis => 0,
             TokenKind::Semicolon => 1,
             TokenKind::Comma => 0,
-            _ => { panic!("rbp (right binding power) is not defined for {:?}", token); }
+            TokenKind::True => 0,
+            TokenKind::False => 0,
+            TokenKind::Null => 0,
         };
     }
     0
@@ -153,6 +276,65 @@ pub fn parse_expression(
     Ok(left)
 }
 
+/// Parses expression and records where it starts, for locating evaluation errors
+pub fn parse_statement(
+    current_rbp: u32,
+    lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+    let location = lexer.peek_or_err()?.source_ref.clone();
+    if let Some(block) = parse_empty_block(lexer) {
+        return Ok(LocatedStatement::rc(block, location));
+    }
+    let expression = parse_expression(current_rbp, lexer)?;
+    // Value directly followed by a literal, like `1 true`, is a missing separator
+    let ends_with_value = matches!(lexer.current().token_kind,
+        TokenKind::Integer(_) | TokenKind::Float(_) | TokenKind::Str(_) | TokenKind::InterpolatedStr(_) |
+        TokenKind::True | TokenKind::False | TokenKind::Null | TokenKind::Identifier(_) |
+        TokenKind::RightParens | TokenKind::RightBracket);
+    if let Some(next) = lexer.peek() {
+        let literal = matches!(next.token_kind,
+            TokenKind::Integer(_) | TokenKind::Float(_) | TokenKind::Str(_) | TokenKind::InterpolatedStr(_) |
+            TokenKind::True | TokenKind::False | TokenKind::Null);
+        if ends_with_value && literal {
+            return Err(ParseError { msg: format!("Expecting ; before {}", next) });
+        }
+    }
+    Ok(LocatedStatement::rc(expression, location))
+}
+
+/// Parses expression with struct construction enabled or disabled, then restores the setting
+pub fn parse_with_structs(allowed: bool, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+    let previous = lexer.allow_structs(allowed);
+    let expression = parse_expression(1, lexer);
+    lexer.allow_structs(previous);
+    expression
+}
+
+/// Parses head of if, while, for and match, where `name {` starts the body, not a struct.
+/// Struct construction is allowed again inside parentheses and brackets.
+pub fn parse_head(lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+    parse_with_structs(false, lexer)
+}
+
+/// Parses body of if, else, loops, functions, try, catch, finally and match arms
+pub fn parse_body(lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+    match parse_empty_block(lexer) {
+        Some(block) => Ok(block),
+        None => parse_expression(1, lexer),
+    }
+}
+
+/// Empty braces are an empty block in statement and body position, elsewhere they are an empty map
+fn parse_empty_block(lexer: &mut Lexer) -> Option<Rc<dyn Expression>> {
+    let left = lexer.peek()?.is_left_brace().is_ok();
+    let right = lexer.peek_nth(1)?.is_right_brace().is_ok();
+    if !(left && right) {
+        return None;
+    }
+    lexer.next();
+    lexer.next();
+    Some(GroupedStatement::rc(vec![]))
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -177,6 +359,12 @@ mod tests {
         evaluate_and_assert("-1", vec![
             TypeMatcher::Integer(&-1),
         ]);
+        evaluate_and_assert("1.5", vec![
+            TypeMatcher::Float(&1.5),
+        ]);
+        evaluate_and_assert("-0.25", vec![
+            TypeMatcher::Float(&-0.25),
+        ]);
         evaluate_and_assert("\"Hello world!\"", vec![
             TypeMatcher::String("Hello world!"),
         ]);
@@ -217,6 +405,36 @@ mod tests {
         evaluate_and_assert("-1 * -1", vec![
             TypeMatcher::Integer(&1),
         ]);
+        evaluate_and_assert("10 / 2", vec![
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_assert("10 % 3", vec![
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_assert("1 + 10 / 2 * 3", vec![
+            TypeMatcher::Integer(&16),
+        ]);
+        evaluate_and_assert("20 / 2 / 5", vec![
+            TypeMatcher::Integer(&2),
+        ]);
+        evaluate_and_assert("2 + 7 % 4", vec![
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_assert("1 + 0.5", vec![
+            TypeMatcher::Float(&1.5),
+        ]);
+        evaluate_and_assert("0.5 * 4", vec![
+            TypeMatcher::Float(&2.0),
+        ]);
+        evaluate_and_assert("7 / 2.0", vec![
+            TypeMatcher::Float(&3.5),
+        ]);
+        evaluate_and_assert("2.0 == 2", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_errors("1 / 0", "Division by zero");
+        evaluate_and_errors("1.5 / 0", "Division by zero");
+        evaluate_and_errors("1 % (1 - 1)", "Division by zero");
     }
 
     #[test]
@@ -236,6 +454,13 @@ mod tests {
         evaluate_and_assert("2* (1 + 1)", vec![
             TypeMatcher::Integer(&4),
         ]);
+        evaluate_and_assert("(6) / (1 + 2) - 1", vec![
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_assert("let a = ((1)) + (2); a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
         evaluate_and_assert("1 == 1", vec![
             TypeMatcher::Boolean(&true),
         ]);
@@ -244,6 +469,140 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_comparison_operators() {
+        evaluate_and_assert("1 < 2", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("1 > 2", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("2 <= 2", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("1 >= 2", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("1.5 < 2", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("\"abc\" < \"abd\"", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("1 + 1 < 1 * 3", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("1 + 1 == 2", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("2 == 1 + 1", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("1 < 2 && 3 >= 3", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("fun a(n) -> if n < 2 { n } else { a(n - 1) + a(n - 2) }; a(10)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&55),
+        ]);
+        evaluate_and_errors("1 < \"a\"", "Can't apply Integer < String");
+        evaluate_and_errors("true > false", "Can't apply Boolean > Boolean");
+    }
+
+    #[test]
+    fn test_string_operators() {
+        evaluate_and_assert("\"a\" + \"b\"", vec![
+            TypeMatcher::String("ab"),
+        ]);
+        evaluate_and_assert("\"n=\" + 5 + \", f=\" + 1.5 + \", b=\" + true + \", l=\" + [1, \"a\"]", vec![
+            TypeMatcher::String("n=5, f=1.5, b=true, l=[1, \"a\"]"),
+        ]);
+        evaluate_and_assert("\"ab\" == \"a\" + \"b\"", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("\"ab\" != \"ab\"", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("\"-\" * 2 * 3", vec![
+            TypeMatcher::String("------"),
+        ]);
+        evaluate_and_assert("\"ab\" * 0", vec![
+            TypeMatcher::String(""),
+        ]);
+        evaluate_and_errors("5 + \"a\"", "Can't apply Integer + String");
+        evaluate_and_errors("\"a\" == 1", "Can't apply String eq/neq Integer");
+        evaluate_and_errors("\"a\" * \"b\"", "Can't apply String * String");
+    }
+
+    #[test]
+    fn test_string_interpolation() {
+        evaluate_and_assert("let n = 10; \"fib(${n}) = ${n * 2 + 1}\"", vec![
+            TypeMatcher::Void,
+            TypeMatcher::String("fib(10) = 21"),
+        ]);
+        evaluate_and_assert("\"${1}${2}\"", vec![
+            TypeMatcher::String("12"),
+        ]);
+        evaluate_and_assert("let m = { \"k\": [1, 2] }; \"m=${m} k=${m[\"k\"][1]}\"", vec![
+            TypeMatcher::Void,
+            TypeMatcher::String("m={\"k\": [1, 2]} k=2"),
+        ]);
+        evaluate_and_assert("\"outer ${\"inner ${1 + 1}\"}\"", vec![
+            TypeMatcher::String("outer inner 2"),
+        ]);
+        evaluate_and_assert("fun f(x) -> { x + 1 }; \"${f(1)} ${if true { \"yes\" } else { \"no\" }}\"", vec![
+            TypeMatcher::Void,
+            TypeMatcher::String("2 yes"),
+        ]);
+        evaluate_and_assert("\"costs $5 or \\${1}\"", vec![
+            TypeMatcher::String("costs $5 or ${1}"),
+        ]);
+        evaluate_and_errors("\"${nope}\"", "Can't resolve variable `nope`");
+    }
+
+    #[test]
+    fn test_logical_operators() {
+        evaluate_and_assert("!true", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("!!true", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("!null", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("true && false", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("false || true", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("false || true && false", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("true || false && false", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("1 == 1 && 2 != 1", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("!false && !(1 == 2)", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_assert("fun a() -> true; !a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Boolean(&false),
+        ]);
+        // Short circuiting, b is never resolved
+        evaluate_and_assert("false && b", vec![
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert("true || b", vec![
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_errors("true && b", "Can't resolve variable `b`");
+    }
+
     #[test]
     fn parse_let_statement() {
         evaluate_and_assert("let a = 1", vec![
@@ -271,6 +630,61 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn assign_to_enclosing_scope() {
+        evaluate_and_assert("let a = 1; fun b() -> { a = a + 1; }; b(); b(); a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("let sum = 0; for i in 0..5 { sum = sum + i; }; sum", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&10),
+        ]);
+        evaluate_and_assert("fun counter() -> { let count = 0; fun () -> { count = count + 1; count } }; let c = counter(); c(); c(); c()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+            TypeMatcher::Integer(&2),
+            TypeMatcher::Integer(&3),
+        ]);
+        // Parameter shadows the outer binding, so the outer one is left untouched
+        evaluate_and_assert("let a = 1; fun b(a) -> { a = 5; a }; b(2); a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&5),
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_errors("const a = 1; fun b() -> { a = 2; }; b()", "Can't assign to constant `a`");
+        evaluate_and_errors("fun b() -> { a = 2; }; b()", "Can't assing to variable `a`");
+    }
+
+    #[test]
+    fn parse_const_statement() {
+        evaluate_and_assert("const a = 1; a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_assert("const a = 1; fun b() -> a; b()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_errors("const a = 1; a = 2", "Can't assign to constant `a`");
+        evaluate_and_errors("const a = 1; let a = 2; a = 3", "Can't redeclare constant `a`");
+        evaluate_and_errors("const a = 1; const a = 2", "Can't redeclare constant `a`");
+        // Constant can be shadowed in a sub scope
+        evaluate_and_assert("const a = 1; fun f() -> { let a = 2; a = 3; a }; f(); a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+            TypeMatcher::Integer(&1),
+        ]);
+    }
+
     #[test]
     fn parse_fun_statement() {
         evaluate_and_assert("fun a() -> 1", vec![
@@ -350,6 +764,43 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn lexical_closures() {
+        evaluate_and_assert("fun adder(n) -> fun (x) -> x + n; let add2 = adder(2); let add5 = adder(5); add2(3); add5(3)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&5),
+            TypeMatcher::Integer(&8),
+        ]);
+        evaluate_and_assert("fun counter() -> { let count = [0]; fun () -> { count[0] = count[0] + 1; count[0] } }; let a = counter(); let b = counter(); a(); a(); b()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+            TypeMatcher::Integer(&2),
+            TypeMatcher::Integer(&1),
+        ]);
+        // Variables are resolved where the function is defined, not where it is called
+        evaluate_and_assert("let x = 1; fun f() -> x; fun g() -> { let x = 2; f() }; g()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_errors("fun f() -> y; fun g() -> { let y = 1; f() }; g()", "Can't resolve variable `y`");
+        // Bindings declared after the function are visible, because the scope is shared
+        evaluate_and_assert("fun f() -> later; let later = 5; f()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_assert("fun f(n) -> { fun inner(m) -> if m == 0 { n } else { inner(m - 1) }; inner(3) }; f(7)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&7),
+        ]);
+    }
+
     #[test]
     fn anonymous_function() {
         evaluate_and_assert("let a = fun (a) -> a + 1; a(1)", vec![
@@ -399,6 +850,606 @@ mod tests {
             TypeMatcher::Integer(&1),
             TypeMatcher::Integer(&2),
         ]);
+        evaluate_and_assert("fun a(b) -> { if b { return 1; } else { return 2; } }; a(true); a(false);", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+            TypeMatcher::Integer(&2),
+        ]);
+        evaluate_and_assert("fun a(b) -> if b == 1 { return 1; } else if b == 2 { return 2; } else { return 3; }; a(1); a(2); a(3);", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+            TypeMatcher::Integer(&2),
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("fun a() -> { if false { return 1; } else if false { return 2; } return 3; }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+    }
+
+    #[test]
+    fn test_if_expressions() {
+        evaluate_and_assert("let a = if true { 1 } else { 2 }; a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_assert("let a = if false { 1 } else { 2 }; a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&2),
+        ]);
+        evaluate_and_assert("if false { 1 }", vec![
+            TypeMatcher::Void,
+        ]);
+        evaluate_and_assert("1 + if true { let a = 1; a + 1 } else { 5 }", vec![
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("fun a(b) -> if b == 1 { 10 } else if b == 2 { 20 } else { 30 }; a(1); a(2); a(3)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&10),
+            TypeMatcher::Integer(&20),
+            TypeMatcher::Integer(&30),
+        ]);
+        parses_to_error("if true { 1 }; else { 2 }", "Can't parse else in prefix position");
+    }
+
+    #[test]
+    fn test_while_loops() {
+        evaluate_and_assert("let i = 0; while i < 10 { i = i + 1 }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&10),
+        ]);
+        evaluate_and_assert("let i = 0; while false { i = i + 1 }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&0),
+        ]);
+        evaluate_and_assert("let i = 0; while true { i = i + 1; if i == 5 { break; } }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_assert("let i = 0; let sum = 0; while i < 10 { i = i + 1; if i % 2 == 0 { continue } sum = sum + i; }; sum", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&25),
+        ]);
+        evaluate_and_assert("fun a() -> { let i = 0; while true { i = i + 1; if i == 3 { return i; } } }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+        // Deep iteration does not blow the stack like recursion does
+        evaluate_and_assert("let i = 0; while i < 100000 { i = i + 1 }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&100000),
+        ]);
+    }
+
+    #[test]
+    fn test_labeled_loops() {
+        evaluate_and_assert("let i = 0; let j = 0; let count = 0; outer: while i < 3 { i = i + 1; j = 0; while j < 3 { j = j + 1; if j == 2 { continue outer; } count = count + 1; } }; count", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("let i = 0; outer: while true { while true { i = i + 1; break outer; } i = 100; }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_assert("let i = 0; outer: while i < 2 { i = i + 1; while true { break; } }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&2),
+        ]);
+    }
+
+    #[test]
+    fn test_for_loops() {
+        evaluate_and_assert("fun a() -> { for i in 0..10 { if i * i > 20 { return i; } } }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_assert("fun a(n) -> { for i in 1..=n { if i == n { return i; } } }; a(3)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("fun a() -> { for c in \"abc\" { if c > \"a\" { return c; } } }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::String("b"),
+        ]);
+        evaluate_and_assert("fun a() -> { for i in 0..5 { if i < 3 { continue; } return i; } }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("fun a() -> { outer: for i in 1..4 { for j in 1..4 { if j == 2 { continue outer; } if i == 3 { return i * 10 + j; } } } }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&31),
+        ]);
+        evaluate_and_assert("for i in 0..3 { break; }", vec![
+            TypeMatcher::Void,
+        ]);
+        // Loop variable does not leak out of the loop
+        evaluate_and_errors("for i in 0..3 { i }; i", "Can't resolve variable `i`");
+        evaluate_and_errors("for i in 1 { i }", "Integer is not iterable");
+        evaluate_and_assert("0..3 == 0..3; 0..3 != 0..=3", vec![
+            TypeMatcher::Boolean(&true),
+            TypeMatcher::Boolean(&true),
+        ]);
+        evaluate_and_errors("for i in 0..\"a\" { i }", "Can't apply Integer .. String");
+        parses_to_error("in [1, 2]", "Can't parse in in prefix position");
+    }
+
+    #[test]
+    fn test_ranges() {
+        evaluate_and_assert("0..5", vec![
+            TypeMatcher::Range(&0, &5, false),
+        ]);
+        evaluate_and_assert("1 + 1..=2 * 3", vec![
+            TypeMatcher::Range(&2, &6, true),
+        ]);
+    }
+
+    #[test]
+    fn test_lists() {
+        evaluate_and_assert_display("[]", vec!["[]"]);
+        evaluate_and_assert_display("[1, 2 + 3, \"a\", [true]]", vec!["[1, 5, \"a\", [true]]"]);
+        evaluate_and_assert_display("let xs = [1, 2, 3]; xs[0]; xs[1 + 1]", vec!["Void", "1", "3"]);
+        evaluate_and_assert_display("[[1, 2], [3, 4]][1][0]", vec!["3"]);
+        evaluate_and_assert_display("let xs = [1, 2, 3]; xs[1] = 5; xs", vec!["Void", "Void", "[1, 5, 3]"]);
+        evaluate_and_assert_display("let xs = [[1]]; xs[0][0] = 2; xs", vec!["Void", "Void", "[[2]]"]);
+        // Lists are shared, not copied
+        evaluate_and_assert_display("let xs = [1]; let ys = xs; ys[0] = 2; xs", vec!["Void", "Void", "Void", "[2]"]);
+        evaluate_and_assert_display("fun a(xs) -> { for x in xs { if x > 1 { return x; } } }; a([1, 2, 3])", vec!["Void", "2"]);
+        evaluate_and_errors("let xs = [1, 2, 3]; xs[3]", "Index 3 out of range for length 3");
+        evaluate_and_errors("let xs = [1, 2, 3]; xs[-1] = 1", "Index -1 out of range for length 3");
+        evaluate_and_errors("[1][\"a\"]", "Can't index List with String");
+        evaluate_and_errors("1[0]", "Integer can't be indexed");
+        evaluate_and_assert_display("let a = [1]; a[0] = a; a", vec!["Void", "Void", "[[...]]"]);
+        evaluate_and_assert_display("[1, 2,]", vec!["[1, 2]"]);
+        parses_to_error("[1 2 3]", "Expecting , or ] but Integer(2) [1:3] found");
+    }
+
+    #[test]
+    fn test_maps() {
+        evaluate_and_assert_display("{:}", vec!["{}"]);
+        evaluate_and_assert_display("{ \"a\": 1, \"b\": 1 + 1 }", vec!["{\"a\": 1, \"b\": 2}"]);
+        evaluate_and_assert_display("{ 1: \"one\", true: [1] }", vec!["{1: \"one\", true: [1]}"]);
+        evaluate_and_assert_display("let m = { \"a\": 1 }; m[\"a\"]", vec!["Void", "1"]);
+        evaluate_and_assert_display("let m = {}; m[\"a\"] = 1; m[\"b\"] = 2; m[\"a\"] = 3; m", vec!["Void", "Void", "Void", "Void", "{\"a\": 3, \"b\": 2}"]);
+        evaluate_and_assert_display("let m = { \"a\": { \"b\": 1 } }; m[\"a\"][\"b\"]", vec!["Void", "1"]);
+        evaluate_and_assert_display("fun a(m) -> { for k in m { if m[k] == 2 { return k; } } }; a({ \"x\": 1, \"y\": 2 })", vec!["Void", "y"]);
+        // Blocks are still blocks
+        evaluate_and_assert_display("{ 1; 2 }", vec!["2"]);
+        evaluate_and_assert_display("{ let a = 1; a }", vec!["1"]);
+        evaluate_and_errors("let m = { \"a\": 1 }; m[\"b\"]", "Key \"b\" not found");
+        evaluate_and_errors("let m = {:}; m[1.5] = 1", "Float can't be used as map key");
+        evaluate_and_errors("{ \"a\": 1, [1]: 1 }", "List can't be used as map key");
+        // Keys are expressions, empty braces are a map in value position
+        evaluate_and_assert_display("let k = 1; {k: 2, k + 1: 3,}", vec!["Void", "{1: 2, 2: 3}"]);
+        evaluate_and_assert_display("let m = {}; m; fun f() -> {}; f(); if true {} else {}; {}", vec!["Void", "{}", "Void", "Void", "Void", "Void"]);
+        evaluate_and_errors("{1.5: 2}", "Float can't be used as map key");
+        evaluate_and_errors("{[1]: 2}", "List can't be used as map key");
+        parses_to_error("{ \"a\": 1 \"b\": 2 }", "Expecting , or } but Str(\"b\") [1:9] found");
+        // Maps are equal structurally, regardless of insertion order
+        evaluate_and_assert("{ \"a\": 1, \"b\": \"c\" } == { \"b\": \"c\", \"a\": 1 }; {1: 1} != {1: 2}; {1: 1} == {}", vec![
+            TypeMatcher::Boolean(&true),
+            TypeMatcher::Boolean(&true),
+            TypeMatcher::Boolean(&false),
+        ]);
+        evaluate_and_assert_display("let m = {}; m[1] = m; m; m == m", vec!["Void", "Void", "{1: {...}}", "true"]);
+    }
+
+    #[test]
+    fn test_methods() {
+        evaluate_and_assert("\"hello\".len()", vec![
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_assert("let a = \"hello\"; a.upper()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::String("HELLO"),
+        ]);
+        evaluate_and_assert("\"a b c\".split(\" \").len() * 2", vec![
+            TypeMatcher::Integer(&6),
+        ]);
+        evaluate_and_assert_display("\"a,b\".split(\",\")", vec!["[\"a\", \"b\"]"]);
+        evaluate_and_assert_display("\"a,b\".split(\",\")[1].upper()", vec!["B"]);
+        evaluate_and_assert_display("let xs = [1]; xs.push(1 + 1); xs.push([3]); xs; xs.len()", vec!["Void", "Void", "Void", "[1, 2, [3]]", "3"]);
+        evaluate_and_assert_display("let xs = []; for i in 0..3 { xs.push(i * i) }; xs", vec!["Void", "Void", "[0, 1, 4]"]);
+        evaluate_and_errors("\"a\".foo()", "String has no member `foo`");
+        evaluate_and_errors("[1].len", "List has no member `len`");
+        evaluate_and_errors("[1].push()", "Expecting 1 arguments for call but 0 given");
+        evaluate_and_errors("1.5.len()", "Float has no member `len`");
+    }
+
+    #[test]
+    fn test_loop_control_outside_of_loop() {
+        evaluate_and_errors("fun a() -> { break; }; a()", "`break` outside of loop");
+        evaluate_and_errors("fun a() -> { continue; }; a()", "`continue` outside of loop");
+        evaluate_and_errors("fun a() -> { break foo; }; while true { a() }", "`break foo` outside of loop");
+        evaluate_and_errors("break; 5", "`break` outside of loop");
+        evaluate_and_errors("let i = 0; if i == 0 { continue; }", "`continue` outside of loop");
+        evaluate_and_errors("while true { fun a() -> { break; }; a() }", "`break` outside of loop");
+        evaluate_and_errors("let i = 0; while i < 3 { i += 1; break nope; }", "Unknown label `nope`");
+        evaluate_and_errors("outer: for x in 0..2 { while true { continue inner; } }", "Unknown label `inner`");
+        evaluate_and_assert("let i = 0; outer: while i < 3 { i += 1; for x in 0..2 { if x == 1 { continue outer; } } }; i", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+        ]);
+        parses_to_error("x: 2", "Can't parse : in prefix position");
+    }
+
+    #[test]
+    fn test_grouped_statement_values() {
+        evaluate_and_assert("{ 1; 2; 3 }", vec![
+            TypeMatcher::Integer(&3),
+        ]);
+        evaluate_and_assert("{ let a = 1; }", vec![
+            TypeMatcher::Void,
+        ]);
+        evaluate_and_assert("{}", vec![
+            TypeMatcher::Void,
+        ]);
+        evaluate_and_assert("fun a() -> { let b = 1; b + 1 }; a()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&2),
+        ]);
+        // Return from inner call must not return from the caller
+        evaluate_and_assert("fun a() -> { return 1; }; fun b() -> { a(); 2 }; b()", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&2),
+        ]);
+        evaluate_and_assert("fun a(b) -> { if b { if true { return 1; } } 2 }; a(true); a(false)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+            TypeMatcher::Integer(&2),
+        ]);
+    }
+
+    #[test]
+    fn test_match() {
+        evaluate_and_assert("fun describe(v) -> match v { 1 -> \"one\", -1 -> \"minus one\", \"a\" -> \"letter\", true -> \"yes\", null -> \"nothing\", _ -> \"other\" };\
+                             describe(1); describe(-1); describe(\"a\"); describe(true); describe(null); describe(2.5)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::String("one"),
+            TypeMatcher::String("minus one"),
+            TypeMatcher::String("letter"),
+            TypeMatcher::String("yes"),
+            TypeMatcher::String("nothing"),
+            TypeMatcher::String("other"),
+        ]);
+        // Binding pattern, arms with blocks and match as a value
+        evaluate_and_assert("let n = 1; let x = match n + 1 { 1 -> 0, n -> { let m = n * 10; m } }; x; n", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&20),
+            TypeMatcher::Integer(&1),
+        ]);
+        evaluate_and_assert("fun a(v) -> { match v { 1 -> { return 10; } _ -> 0 }; 5 }; a(1); a(2)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&10),
+            TypeMatcher::Integer(&5),
+        ]);
+        evaluate_and_errors("match 3 { 1 -> 1, 2 -> 2 }", "No match arm for 3");
+        evaluate_and_errors("match \"c\" { \"a\" -> 1 }", "No match arm for \"c\"");
+        parses_to_error("1 -> 2", "Can't parse -> in prefix position");
+    }
+
+    #[test]
+    fn test_structs() {
+        evaluate_and_assert_display("struct Point { x, y }; let p = Point { y: 2, x: 1 }; p; p.x + p.y; Point", vec![
+            "Void",
+            "Void",
+            "Point { x: 1, y: 2 }",
+            "3",
+            "struct Point",
+        ]);
+        // Structs are shared by reference
+        evaluate_and_assert_display("struct Point { x, y, }; let p = Point { x: 1, y: 2 }; let q = p; q.x = 5; p", vec![
+            "Void",
+            "Void",
+            "Void",
+            "Void",
+            "Point { x: 5, y: 2 }",
+        ]);
+        evaluate_and_assert("struct P { x }; P { x: 1 } == P { x: 1 }; P { x: 1 } != P { x: 2 }", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Boolean(&true),
+            TypeMatcher::Boolean(&true),
+        ]);
+        // Block starting with labeled loop is not a struct
+        evaluate_and_assert("let a = 0; if true { outer: while true { a = 1; break outer; } }; a", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&1),
+        ]);
+        // Brace after the head of if, while, for and match starts the body, not a struct
+        evaluate_and_assert_display("struct P { x }; let x = 5; let p = true; if p { x: 1 }; let xs = [1]; for i in xs { i: 2 }", vec![
+            "Void",
+            "Void",
+            "Void",
+            "{5: 1}",
+            "Void",
+            "Void",
+        ]);
+        evaluate_and_assert_display("struct P { x }; let p = P { x: 1 }; if p == (P { x: 1 }) { [P { x: 2 }] }; match p { q -> q.x }", vec![
+            "Void",
+            "Void",
+            "[P { x: 2 }]",
+            "1",
+        ]);
+        // Struct containing itself
+        evaluate_and_assert_display("struct P { x }; let p = P { x: 1 }; p.x = p; p; p == p", vec![
+            "Void",
+            "Void",
+            "Void",
+            "P { x: P { ... } }",
+            "true",
+        ]);
+        parses_to_error("struct P { x, y, x }", "Field `x` declared twice in struct P");
+        // Same named structs declared in different scopes with different fields
+        evaluate_and_errors("struct P { x }; let p = P { x: 1 }; fun f() -> { struct P { y }; P { y: 1 } }; p == f()",
+                            "Can't apply P eq/neq P");
+        evaluate_and_errors("struct P { x }; P { x: 1 }.y", "P has no member `y`");
+        evaluate_and_errors("struct P { x }; let p = P { x: 1 }; p.y = 1", "P has no member `y`");
+        evaluate_and_errors("struct P { x, y }; P { x: 1 }", "Missing field `y` for struct P");
+        evaluate_and_errors("let P = 1; P { x: 1 }", "Integer is not a struct");
+        evaluate_and_errors("struct P { x }; P { x: 1 } == P { x: true }", "Can't apply Integer eq/neq Boolean");
+    }
+
+    #[test]
+    fn test_imports() {
+        // Without file, imports are relative to working directory
+        evaluate_and_assert("import \"examples/lib/math.bs\"; import \"examples/lib/math.bs\" as m; square(3); m.gcd(12, 18); m.answer", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&9),
+            TypeMatcher::Integer(&6),
+            TypeMatcher::Integer(&42),
+        ]);
+        evaluate_and_errors("import \"examples/lib/math.bs\" as m; m.nope", "Module has no member `nope`");
+        // Module functions take named arguments and spread like any call
+        evaluate_and_assert("import \"examples/lib/math.bs\" as m; m.gcd(12, b: 18); m.gcd(b: 18, a: 12); m.gcd(...[12, 18])", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&6),
+            TypeMatcher::Integer(&6),
+            TypeMatcher::Integer(&6),
+        ]);
+        evaluate_and_errors("import \"examples/lib/math.bs\" as m; m.gcd(12, c: 18)", "No parameter named `c`");
+        evaluate_and_errors("let xs = []; xs.push(x: 1)", "Method `push` of List takes no named arguments, `x` given");
+        parses_to_error("as;", "Can't parse as in prefix position");
+    }
+
+    #[test]
+    fn test_exceptions() {
+        evaluate_and_assert_display("try { throw \"boom\"; 1 } catch e { e }; try { 1 } catch e { 2 }", vec![
+            "boom",
+            "1",
+        ]);
+        // Runtime errors are caught as Error values with location
+        evaluate_and_assert_display("fun f(a, b) -> a + b;\ntry {\n  let a = 1;\n  f(1)\n} catch e { e }; try { x } catch e { e.message }", vec![
+            "Void",
+            "Error { message: \"Missing argument for parameter `b`\", line: 4, column: 2 }",
+            "Can't resolve variable `x`",
+        ]);
+        // Error is located at the innermost statement, also inside functions
+        evaluate_and_assert_display("fun f() -> {\n  let a = 1;\n    a.b\n};\ntry { f() } catch e { [e.line, e.column] }", vec![
+            "Void",
+            "[3, 4]",
+        ]);
+        evaluate_and_assert_display("let log = []; fun f() -> { try { return 1; } finally { log.push(\"finally\") } }; f(); log", vec![
+            "Void",
+            "Void",
+            "1",
+            "[\"finally\"]",
+        ]);
+        evaluate_and_assert_display("let n = 0; while true { try { throw 1 } catch e { n = n + 1 } finally { if n > 2 { break; } } }; n", vec![
+            "Void",
+            "Void",
+            "3",
+        ]);
+        evaluate_and_errors("try { throw [1] } finally { 1 }", "Uncaught exception [1]");
+        evaluate_and_errors("try { 1 } catch e { 2 } finally { throw \"finally\" }", "Uncaught exception \"finally\"");
+        parses_to_error("catch;", "Can't parse catch in prefix position");
+        parses_to_error("finally { 2 }", "Can't parse finally in prefix position");
+    }
+
+    #[test]
+    fn test_default_and_named_arguments() {
+        evaluate_and_assert_display(
+            "fun greet(name, greeting = \"Hello\") -> \"${greeting} ${name}\";\
+             greet(\"x\"); greet(\"x\", \"Hi\"); greet(name: \"y\"); greet(greeting: \"Yo\", name: \"z\")",
+            vec!["Void", "Hello x", "Hi x", "Hello y", "Yo z"]);
+        // Defaults may refer to parameters before them
+        evaluate_and_assert("fun f(a, b = a * 2) -> a + b; f(1); f(1, b: 1)", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&3),
+            TypeMatcher::Integer(&2),
+        ]);
+        evaluate_and_errors("fun f(a, b) -> a; f()", "Missing arguments for parameters `a`, `b`");
+        evaluate_and_errors("fun f(a, b = 1) -> a; f(b: 2)", "Missing argument for parameter `a`");
+        evaluate_and_errors("fun f(a) -> a; f(1, 2)", "Expecting 1 arguments for call but 2 given");
+        evaluate_and_errors("fun f(a) -> a; f(c: 1)", "No parameter named `c`");
+        evaluate_and_errors("fun f(a) -> a; f(1, a: 1)", "Argument for parameter `a` given twice");
+        evaluate_and_errors("\"a\".len(a: 1)", "Method `len` of String takes no named arguments, `a` given");
+        for (input, expected) in [
+            ("fun f(a = 1, b) -> a", "Parameter `b` without default can't follow parameters with defaults"),
+            ("f(a: 1, 2)", "Positional argument can't follow named argument"),
+        ] {
+            match Lexer::new(input) {
+                Err(e) => panic!("Lexing failed: {}", e),
+                Ok(mut lexer) => match Parser::new(&mut lexer).parse() {
+                    Ok(_) => panic!("Expected parsing to fail, input: {}", input),
+                    Err(e) => assert_eq!(e.msg, expected, "Right from input: {}", input),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_separators() {
+        parses_to_error("1 true", "Expecting ; before True [1:2]");
+        parses_to_error("1 false", "Expecting ; before False [1:2]");
+        parses_to_error("let a = 1 null", "Expecting ; before Null [1:10]");
+        parses_to_error("f(1) 2", "Expecting ; before Integer(2) [1:5]");
+        // Statements not starting with a literal don't need a separator
+        evaluate_and_assert("let a = 1 let b = a a + b", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&2),
+        ]);
+    }
+
+    #[test]
+    fn test_rest_parameters_and_spread() {
+        evaluate_and_assert_display(
+            "fun f(first, ...rest) -> rest; f(1); f(1, 2, 3); fun g(a, b = 2, ...rest) -> [a, b, rest]; g(1, b: 3)",
+            vec!["Void", "[]", "[2, 3]", "Void", "[1, 3, []]"]);
+        evaluate_and_assert(
+            "let xs = [1, 2, 3]; fun add(a, b, c) -> a + b + c; add(...xs); add(1, ...[2], 3); add(...1..4)",
+            vec![
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::Integer(&6),
+                TypeMatcher::Integer(&6),
+                TypeMatcher::Integer(&6),
+            ]);
+        evaluate_and_errors("fun f(a) -> a; f(...[1, 2])", "Expecting 1 arguments for call but 2 given");
+        evaluate_and_errors("fun f(...rest) -> rest; f(rest: 1)", "No parameter named `rest`");
+        evaluate_and_errors("fun f(a) -> a; f(...1)", "Integer is not iterable");
+        parses_to_error("fun f(...rest, a) -> a", "Rest parameter must be the last parameter");
+        // Spread works for built-in methods too
+        evaluate_and_assert_display("\"a,b\".split(...[\",\"]); \"a\".len(...[])", vec![
+            "[\"a\", \"b\"]",
+            "1",
+        ]);
+        parses_to_error("...x;", "Can't parse ... in prefix position");
+        parses_to_error("[...x]", "Can't parse ... in prefix position");
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        evaluate_and_assert(
+            "let a = 10; a += 5; a -= 3; a *= 2; a /= 4; a; let s = \"ab\"; s *= 2; s += \"!\"; s",
+            vec![
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::Integer(&6),
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::Void,
+                TypeMatcher::String("abab!"),
+            ]);
+        // Binding is updated in the scope where it was declared
+        evaluate_and_assert("let n = 0; fun inc() -> { n += 2 }; inc(); inc(); n", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Integer(&4),
+        ]);
+        evaluate_and_assert("let f = 1.5; f *= 2; f", vec![
+            TypeMatcher::Void,
+            TypeMatcher::Void,
+            TypeMatcher::Float(&3.0),
+        ]);
+        evaluate_and_errors("const c = 1; c += 1", "Can't assign to constant `c`");
+        evaluate_and_errors("x -= 1", "Can't assing to variable `x`");
+        evaluate_and_errors("let a = 1; a /= 0", "Division by zero");
+        // Right hand side is evaluated before the current value is read
+        evaluate_and_assert_display("let x = 1; fun f() -> { x = 10; 1 }; x += f(); x; let xs = [1]; fun g() -> { xs[0] = 10; 1 }; xs[0] += g(); xs", vec![
+            "Void",
+            "Void",
+            "Void",
+            "11",
+            "Void",
+            "Void",
+            "Void",
+            "[11]",
+        ]);
+        evaluate_and_assert_display("struct P { x }; let p = P { x: 1 }; fun h() -> { p.x = 10; 1 }; p.x += h(); p.x", vec![
+            "Void",
+            "Void",
+            "Void",
+            "Void",
+            "11",
+        ]);
+        evaluate_and_errors("let a = 2147483647; a += 1", "Integer overflow");
+        evaluate_and_errors("let a = 65536; a *= a", "Integer overflow");
+        evaluate_and_errors("let a = true; a += 1", "Can't apply Boolean + Integer");
+        parses_to_error("1 += 2", "Expecting identifier, index or member before +=");
+        // Index and member targets, target and index are evaluated once
+        evaluate_and_assert_display("let xs = [1, 2]; xs[0] += 5; xs[1] *= 3; xs; let m = {\"a\": 1}; m[\"a\"] -= 2; m", vec![
+            "Void",
+            "Void",
+            "Void",
+            "[6, 6]",
+            "Void",
+            "Void",
+            "{\"a\": -1}",
+        ]);
+        evaluate_and_assert_display("let calls = 0; let xs = [10, 20]; fun at() -> { calls += 1; xs }; at()[calls] += 1; xs; calls", vec![
+            "Void",
+            "Void",
+            "Void",
+            "Void",
+            "[10, 21]",
+            "1",
+        ]);
+        evaluate_and_assert_display("struct P { x }; let p = P { x: 1 }; p.x += 1; p.x /= 2; p", vec![
+            "Void",
+            "Void",
+            "Void",
+            "Void",
+            "P { x: 1 }",
+        ]);
+        evaluate_and_errors("let xs = [1]; xs[1] += 1", "Index 1 out of range for length 1");
+        evaluate_and_errors("struct P { x }; let p = P { x: 1 }; p.y += 1", "P has no member `y`");
+    }
+
+    #[test]
+    fn test_uncaught_errors_are_located() {
+        let input = "let a = 1;\n  a + b";
+        match Lexer::new(input) {
+            Err(e) => panic!("Lexing failed: {}", e),
+            Ok(mut lexer) => match Parser::new(&mut lexer).parse() {
+                Err(e) => panic!("Parse error: {}", e),
+                Ok(things) => {
+                    let mut scope = Scope::new();
+                    assert!(things[0].evaluate(&mut scope).is_ok());
+                    match things[1].evaluate(&mut scope) {
+                        Ok(_) => panic!("Expected evaluation to fail"),
+                        Err(e) => assert_eq!(format!("{}", e),
+                                             "Evaluation Error: Can't resolve variable `b` @ [2:2]"),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_doc_comments_attach_to_declarations() {
+        let input = "/// Adds numbers\nfun add(a, b) -> a + b; let c = 1; /// The answer\nconst d = 42;";
+        match Lexer::new(input) {
+            Err(e) => panic!("Lexing failed: {}", e),
+            Ok(mut lexer) => match Parser::new(&mut lexer).parse() {
+                Err(e) => panic!("Parse error: {}", e),
+                Ok(things) => {
+                    assert_eq!(things[0].doc(), Some(&"Adds numbers".to_string()));
+                    assert_eq!(things[1].doc(), None);
+                    assert_eq!(things[2].doc(), Some(&"The answer".to_string()));
+                }
+            }
+        }
     }
 
     #[test]
@@ -414,6 +1465,38 @@ mod tests {
         ]);
     }
 
+    fn parses_to_error(input: &str, expected_msg: &str) {
+        match Lexer::new(input) {
+            Err(e) => panic!("Lexing failed: {}", e),
+            Ok(mut lexer) => match Parser::new(&mut lexer).parse() {
+                Ok(_) => panic!("Expected parsing to fail, input: {}", input),
+                Err(e) => assert_eq!(e.msg, expected_msg, "Right from input: {}", input),
+            }
+        }
+    }
+
+    fn evaluate_and_errors(input: &str, expected_msg: &str) {
+        match Lexer::new(input) {
+            Err(e) => panic!("Lexing failed: {}", e),
+            Ok(mut lexer) => {
+                let mut parser = Parser::new(&mut lexer);
+                match parser.parse() {
+                    Err(e) => panic!("Parse error: {}", e),
+                    Ok(things) => {
+                        let mut scope = Scope::new();
+                        for received_expression in things.iter() {
+                            if let Err(e) = received_expression.evaluate(&mut scope) {
+                                assert_eq!(e.msg, expected_msg, "Right from input: {}", input);
+                                return;
+                            }
+                        }
+                        panic!("Expected evaluation to fail, input: {}", input)
+                    }
+                }
+            }
+        }
+    }
+
     fn evaluate_and_assert(input: &str, expected: Vec<TypeMatcher>) {
         match Lexer::new(input) {
             Err(e) => panic!("Lexing failed: {}", e),
@@ -437,4 +1520,28 @@ mod tests {
             }
         }
     }
+    /// Asserts displayed values, handy for compound values that are awkward to build as TypeMatchers
+    fn evaluate_and_assert_display(input: &str, expected: Vec<&str>) {
+        match Lexer::new(input) {
+            Err(e) => panic!("Lexing failed: {}", e),
+            Ok(mut lexer) => {
+                let mut parser = Parser::new(&mut lexer);
+                match parser.parse() {
+                    Err(e) => panic!("Parse error: {}", e),
+                    Ok(things) => {
+                        assert_eq!(things.len(), expected.len());
+                        let mut scope = Scope::new();
+                        for (index, received_expression) in things.iter().enumerate() {
+                            match received_expression.evaluate(&mut scope) {
+                                Ok(res) =>
+                                    assert_eq!(format!("{}", res.type_matcher()), *expected.get(index).unwrap(),
+                                               "Right from input: {}", input),
+                                Err(e) => panic!("Eval error: {:?} input: {}", e, input)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
\ No newline at end of file
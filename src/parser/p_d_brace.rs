@@ -1,27 +1,80 @@
-use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::parser::{Parselet, ParseError, parse_expression, parse_statement};
 use crate::lexer::Lexer;
 use crate::ast::Expression;
 use std::rc::Rc;
 
 use crate::ast::s_grouped::GroupedStatement;
+use crate::ast::v_map::MapExpression;
+use crate::ast::s_located::LocatedStatement;
 
 pub struct LeftBraceParselet {}
 
-impl Parselet for LeftBraceParselet {
-    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
-        let mut children: Vec<Rc<dyn Expression>> = vec![];
+impl LeftBraceParselet {
+    /// Parses rest of the map after the colon following the first key
+    fn parse_map(lexer: &mut Lexer, first_key: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let mut entries: Vec<(Rc<dyn Expression>, Rc<dyn Expression>)> = vec![];
+        let mut key = first_key;
         loop {
+            let value = parse_expression(1, lexer)?;
+            entries.push((key, value));
+            // Entries are separated by commas, trailing comma is allowed
+            let separator = lexer.next_or_err()?;
+            if separator.is_right_brace().is_ok() {
+                break;
+            }
+            if separator.is_comma().is_err() {
+                return Err(ParseError { msg: format!("Expecting , or }} but {} found", separator) });
+            }
             if lexer.peek_or_err()?.is_right_brace().is_ok() {
                 lexer.next();
                 break;
             }
-            children.push(parse_expression(1, lexer)?);
+            key = parse_expression(1, lexer)?;
+            lexer.next_or_err()?.is_colon()?;
+        }
+        Ok(MapExpression::rc(entries))
+    }
+
+    /// Parses rest of the block after its first child
+    fn parse_block(lexer: &mut Lexer, first: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let mut children: Vec<Rc<dyn Expression>> = vec![first];
+        loop {
             if lexer.peek_or_err()?.is_semicolon().is_ok() {
                 lexer.next();
             }
+            if lexer.peek_or_err()?.is_right_brace().is_ok() {
+                lexer.next();
+                break;
+            }
+            children.push(parse_statement(1, lexer)?);
         }
         Ok(GroupedStatement::rc(children))
     }
+}
+
+impl Parselet for LeftBraceParselet {
+    /// Braces are a map when the first expression is followed by colon: `{ key: value }`,
+    /// otherwise they are a block. Empty braces are a map here, bodies and statements
+    /// parse them as an empty block before reaching this.
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        if lexer.peek_or_err()?.is_right_brace().is_ok() {
+            lexer.next();
+            return Ok(MapExpression::rc(vec![]));
+        }
+        // `{:}` is an empty map in any position
+        if lexer.peek_or_err()?.is_colon().is_ok() {
+            lexer.next();
+            lexer.next_or_err()?.is_right_brace()?;
+            return Ok(MapExpression::rc(vec![]));
+        }
+        let location = lexer.peek_or_err()?.source_ref.clone();
+        let first = parse_expression(1, lexer)?;
+        if lexer.peek_or_err()?.is_colon().is_ok() {
+            lexer.next();
+            return LeftBraceParselet::parse_map(lexer, first);
+        }
+        LeftBraceParselet::parse_block(lexer, LocatedStatement::rc(first, location))
+    }
 
     fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
         Err( ParseError { msg: "Can't parse { in LED position".to_string() } )
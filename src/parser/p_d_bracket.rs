@@ -0,0 +1,47 @@
+use crate::parser::{Parselet, ParseError, parse_with_structs};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::v_list::ListExpression;
+use crate::ast::e_index::IndexExpression;
+
+pub struct LeftBracketParselet {}
+
+impl Parselet for LeftBracketParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let mut elements: Vec<Rc<dyn Expression>> = vec![];
+        loop {
+            if lexer.peek_or_err()?.is_right_bracket().is_ok() {
+                lexer.next();
+                break;
+            }
+            elements.push(parse_with_structs(true, lexer)?);
+            // Elements are separated by commas, trailing comma is allowed
+            let separator = lexer.peek_or_err()?;
+            if separator.is_comma().is_ok() {
+                lexer.next();
+            } else if separator.is_right_bracket().is_err() {
+                return Err(ParseError { msg: format!("Expecting , or ] but {} found", separator) });
+            }
+        }
+        Ok(ListExpression::rc(elements))
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let index = parse_with_structs(true, lexer)?;
+        lexer.next_or_err()?.is_right_bracket()?;
+        Ok(IndexExpression::rc(left, index))
+    }
+}
+
+pub struct RightBracketParselet {}
+
+impl Parselet for RightBracketParselet {
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err( ParseError { msg: "Can't parse ] in NUD position".to_string() } )
+    }
+
+    fn led(&self, _lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Ok(left)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::parser::{Parselet, ParseError};
+use crate::parser::p_d_parens::parse_arguments;
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::e_member::MemberExpression;
+use crate::ast::e_method_call::MethodCallExpression;
+
+pub struct DotParselet {}
+
+impl Parselet for DotParselet {
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err( ParseError { msg: "Can't parse . in NUD position".to_string() } )
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let name = lexer.next_or_err()?.is_identifier()?;
+        // `value.name(args)` is a method call, not a call to the value of `value.name`
+        if lexer.peek().is_some_and(|token| token.is_left_parens().is_ok()) {
+            lexer.next();
+            return Ok(MethodCallExpression::rc(left, name, parse_arguments(lexer)?));
+        }
+        Ok(MemberExpression::rc(left, name))
+    }
+}
\ No newline at end of file
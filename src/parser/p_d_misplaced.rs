@@ -0,0 +1,21 @@
+use crate::parser::{Parselet, ParseError};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+
+/// Keyword or punctuation that is only valid as a part of another statement,
+/// like `else` after if. The statement parses it itself, so anywhere else it is an error.
+pub struct MisplacedParselet {
+    pub token: &'static str,
+}
+
+impl Parselet for MisplacedParselet {
+
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err( ParseError { msg: format!("Can't parse {} in prefix position", self.token) } )
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err( ParseError { msg: format!("Can't parse {} in infix position", self.token) } )
+    }
+}
\ No newline at end of file
@@ -1,34 +1,62 @@
-use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::parser::{Parselet, ParseError, parse_with_structs};
 use crate::lexer::Lexer;
 use crate::ast::Expression;
 use std::rc::Rc;
-use crate::ast::e_call::CallExpression;
+use crate::ast::e_call::{CallExpression, Argument};
+use crate::lexer::tokens::TokenKind;
 
 pub struct LeftParensParselet {}
 
+/// Parses comma separated call arguments after `(` until the closing `)`,
+/// `name: value` is a named argument and must come after positional and spread ones
+pub fn parse_arguments(lexer: &mut Lexer) -> Result<Vec<Argument>, ParseError> {
+    let mut args: Vec<Argument> = vec![];
+    loop {
+        if lexer.peek_or_err()?.is_right_parens().is_ok() {
+            lexer.next();
+            break;
+        }
+        let named = match (lexer.peek().map(|t| &t.token_kind), lexer.peek_nth(1)) {
+            (Some(TokenKind::Identifier(name)), Some(colon)) if colon.is_colon().is_ok() => Some(name.clone()),
+            _ => None,
+        };
+        match named {
+            Some(name) => {
+                lexer.next();
+                lexer.next();
+                args.push(Argument::Named(name, parse_with_structs(true, lexer)?));
+            }
+            None => {
+                if args.iter().any(|arg| matches!(arg, Argument::Named(_, _))) {
+                    return Err(ParseError { msg: "Positional argument can't follow named argument".to_string() });
+                }
+                if lexer.peek_or_err()?.token_kind == TokenKind::Ellipsis {
+                    lexer.next();
+                    args.push(Argument::Spread(parse_with_structs(true, lexer)?));
+                } else {
+                    args.push(Argument::Positional(parse_with_structs(true, lexer)?));
+                }
+            }
+        }
+        if lexer.peek_or_err()?.is_comma().is_ok() {
+            lexer.next();
+        }
+    }
+    Ok(args)
+}
+
 impl Parselet for LeftParensParselet {
     fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
-        let expr = parse_expression(
-            0,
-            lexer)?;
+        // Group ends at its own `)`, so that operators after it are not parsed inside the group
+        let expr = parse_with_structs(true, lexer)?;
+        lexer.next_or_err()?.is_right_parens()?;
         Ok(expr)
     }
 
     fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
         // Idea: this could check, that left.is_callable()!!!
         // now this evaluates to Fn `fun () -> 1()`, this `(fun () -> 1)()` works as expected
-        let mut args: Vec<Rc<dyn Expression>> = vec![];
-        loop {
-            if lexer.peek_or_err()?.is_right_parens().is_ok() {
-                lexer.next();
-                break;
-            }
-            args.push(parse_expression(1, lexer)?);
-            if lexer.peek_or_err()?.is_comma().is_ok() {
-                lexer.next();
-            }
-        }
-        Ok(CallExpression::rc(left, args))
+        Ok(CallExpression::rc(left, parse_arguments(lexer)?))
     }
 }
 
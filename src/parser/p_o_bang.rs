@@ -0,0 +1,22 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use std::rc::Rc;
+use crate::ast::Expression;
+use crate::ast::e_not::NotExpression;
+
+pub struct BangParselet {}
+
+impl Parselet for BangParselet {
+
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        // Binds tighter than any infix operator, but calls are still negated as a whole
+        let expression = parse_expression(
+            40,
+            lexer)?;
+        Ok(NotExpression::rc(expression))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse ! in infix position".to_string() })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+
+use std::rc::Rc;
+use crate::ast::e_comparison::{ComparisonExpression, ComparisonOperator};
+
+pub struct ComparisonParselet {
+    pub operator: ComparisonOperator,
+}
+
+impl Parselet for ComparisonParselet {
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: format!("Can't parse {:?} in prefix position", self.operator) })
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let right = parse_expression(
+            7,
+            lexer)?;
+
+        Ok(ComparisonExpression::rc(
+            self.operator,
+            left,
+            right,
+        ))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::e_division::DivisionExpression;
+
+pub struct DivisionParselet {}
+
+impl Parselet for DivisionParselet {
+
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err( ParseError { msg: "Can't parse / in prefix position".to_string() } )
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let right = parse_expression(
+            20,
+            lexer)?;
+
+        Ok(DivisionExpression::rc(
+            left,
+            right,
+        ))
+    }
+}
\ No newline at end of file
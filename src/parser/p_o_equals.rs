@@ -21,7 +21,7 @@ impl Parselet for EqualsParselet {
 
     fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
         let right = parse_expression(
-            5,
+            6,
             lexer)?;
 
         match self.equality_type {
@@ -0,0 +1,43 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::e_logical::{AndExpression, OrExpression};
+
+pub struct AndParselet {}
+
+impl Parselet for AndParselet {
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse && in prefix position".to_string() })
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let right = parse_expression(
+            4,
+            lexer)?;
+
+        Ok(AndExpression::rc(
+            left,
+            right,
+        ))
+    }
+}
+
+pub struct OrParselet {}
+
+impl Parselet for OrParselet {
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse || in prefix position".to_string() })
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let right = parse_expression(
+            3,
+            lexer)?;
+
+        Ok(OrExpression::rc(
+            left,
+            right,
+        ))
+    }
+}
\ No newline at end of file
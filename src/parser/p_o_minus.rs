@@ -10,14 +10,14 @@ impl Parselet for MinusParselet {
 
     fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
         let expression = parse_expression(
-            5,
+            10,
             lexer)?;
         Ok(Rc::new(PrefixMinusExpression::new(expression)))
     }
 
     fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
         let right = parse_expression(
-            5,
+            10,
             lexer)?;
 
         Ok(MinusExpression::rc(
@@ -14,7 +14,7 @@ impl Parselet for MultiplicationParselet {
 
     fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
         let right = parse_expression(
-            10,
+            20,
             lexer)?;
 
         Ok(MultiplicationExpression::rc(
@@ -19,7 +19,7 @@ impl Parselet for PlusParselet {
 
     fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
         let right = parse_expression(
-            5,
+            10,
             lexer)?;
 
         Ok(PlusExpression::rc(
@@ -0,0 +1,27 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use crate::ast::e_range::RangeExpression;
+use std::rc::Rc;
+
+pub struct RangeParselet {
+    pub inclusive: bool,
+}
+
+impl Parselet for RangeParselet {
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse range in prefix position".to_string() })
+    }
+
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        let right = parse_expression(
+            8,
+            lexer)?;
+
+        Ok(RangeExpression::rc(
+            self.inclusive,
+            left,
+            right,
+        ))
+    }
+}
\ No newline at end of file
@@ -2,25 +2,55 @@ use crate::parser::{Parselet, ParseError, parse_expression};
 use crate::lexer::Lexer;
 use crate::ast::Expression;
 use std::rc::Rc;
-use crate::ast::s_assign::AssignStatement;
+use crate::ast::s_assign::{AssignStatement, CompoundOperator};
+use crate::ast::s_index_assign::IndexAssignStatement;
+use crate::ast::s_member_assign::MemberAssignStatement;
 
 
-pub struct AssignParselet {}
+/// Assignment `=`, or compound assignment like `+=` with operator
+pub struct AssignParselet {
+    pub operator: Option<CompoundOperator>,
+}
+
+impl AssignParselet {
+    fn symbol(&self) -> &'static str {
+        self.operator.map_or("=", |operator| operator.symbol())
+    }
+}
 
 impl Parselet for AssignParselet {
 
     fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
-        Err( ParseError { msg: "Can't parse = in NUD position".to_string() })
+        Err( ParseError { msg: format!("Can't parse {} in NUD position", self.symbol()) })
     }
 
-    fn led(&self, _lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+    fn led(&self, lexer: &mut Lexer, left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        if let Ok((target, index)) = left.get_index() {
+            return Ok(
+                IndexAssignStatement::rc(
+                    target.clone(),
+                    index.clone(),
+                    self.operator,
+                    parse_expression(1, lexer)?
+                ));
+        }
+        if let Ok((target, name)) = left.get_member_access() {
+            return Ok(
+                MemberAssignStatement::rc(
+                    target.clone(),
+                    name.clone(),
+                    self.operator,
+                    parse_expression(1, lexer)?
+                ));
+        }
         match left.get_identifier() {
             Ok(identifier) => Ok(
                 AssignStatement::rc(
                     identifier.clone(),
-                    parse_expression(1, _lexer)?
+                    self.operator,
+                    parse_expression(1, lexer)?
                 )),
-            Err(_) => Err( ParseError { msg: "Expecting identifier before =".to_string() })
+            Err(_) => Err( ParseError { msg: format!("Expecting identifier, index or member before {}", self.symbol()) })
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::parser::{Parselet, ParseError};
+use crate::lexer::Lexer;
+use crate::lexer::tokens::TokenKind;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_break::{BreakStatement, ContinueStatement};
+
+/// Identifier right after break or continue is the label of the targeted loop
+fn parse_label(lexer: &mut Lexer) -> Option<String> {
+    let label = match lexer.peek() {
+        Some(token) => match &token.token_kind {
+            TokenKind::Identifier(label) => Some(label.clone()),
+            _ => None
+        },
+        None => None
+    };
+    if label.is_some() {
+        lexer.next();
+    }
+    label
+}
+
+pub struct BreakParselet {}
+
+impl Parselet for BreakParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Ok(BreakStatement::rc(parse_label(lexer)))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse break in infix position".to_string() })
+    }
+}
+
+pub struct ContinueParselet {}
+
+impl Parselet for ContinueParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Ok(ContinueStatement::rc(parse_label(lexer)))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse continue in infix position".to_string() })
+    }
+}
\ No newline at end of file
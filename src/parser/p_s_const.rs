@@ -0,0 +1,32 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_const::ConstStatement;
+
+
+pub struct ConstParselet {}
+
+impl Parselet for ConstParselet {
+
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let doc = lexer.current().doc.clone();
+        let next_token = lexer.next_or_err()?;
+        let identifier = next_token.is_identifier()?;
+        lexer.next_or_err()?.is_assing()?;
+
+        let expr = parse_expression(
+            1,
+            lexer)?;
+
+        Ok(ConstStatement::rc(
+            identifier,
+            expr,
+            doc,
+        ))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse const in infix position".to_string() })
+    }
+}
\ No newline at end of file
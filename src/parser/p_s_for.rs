@@ -0,0 +1,29 @@
+use crate::parser::{Parselet, ParseError, parse_head, parse_body};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_for::ForStatement;
+
+pub struct ForParselet {}
+
+impl ForParselet {
+    /// Parses rest of the for statement after `for` token.
+    /// Labeled loops (`outer: for ...`) are detected in IdentifierParselet.
+    pub fn parse_for(lexer: &mut Lexer, label: Option<String>) -> Result<Rc<dyn Expression>, ParseError> {
+        let identifier = lexer.next_or_err()?.is_identifier()?;
+        lexer.next_or_err()?.is_in()?;
+        let iterable = parse_head(lexer)?;
+        let body = parse_body(lexer)?;
+        Ok(ForStatement::rc(label, identifier, iterable, body))
+    }
+}
+
+impl Parselet for ForParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        ForParselet::parse_for(lexer, None)
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse for in infix position".to_string() })
+    }
+}
\ No newline at end of file
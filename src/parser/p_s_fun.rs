@@ -1,15 +1,15 @@
-use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::parser::{Parselet, ParseError, parse_expression, parse_body};
 use crate::lexer::Lexer;
 use crate::ast::Expression;
 use std::rc::Rc;
-use crate::ast::s_fun::{FunStatement, AnonFunction};
-use crate::ast::e_identifier::IdentifierExpression;
+use crate::ast::s_fun::{FunStatement, AnonFunction, Parameter};
 use crate::lexer::tokens::TokenKind;
 
 pub struct FunParselet {}
 
 impl Parselet for FunParselet {
     fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let doc = lexer.current().doc.clone();
         let identifier = lexer.next_or_err()?.is_identifier();
         match identifier {
             Ok(_) => {
@@ -22,16 +22,31 @@ impl Parselet for FunParselet {
         }
 
 
-        let mut args: Vec<IdentifierExpression> = vec![];
+        let mut args: Vec<Parameter> = vec![];
         loop {
             let token = lexer.next_or_err()?;
             if token.is_right_parens().is_ok() {
                 break;
             }
+            if args.last().is_some_and(|arg| arg.is_rest()) {
+                return Err(ParseError { msg: "Rest parameter must be the last parameter".to_string() });
+            }
 
             match &token.token_kind {
                 TokenKind::Identifier(name) => {
-                    args.push(IdentifierExpression::new(name.clone()))
+                    let name = name.clone();
+                    let default = if lexer.peek().is_some_and(|t| t.is_assing().is_ok()) {
+                        lexer.next();
+                        Some(parse_expression(1, lexer)?)
+                    } else if args.iter().any(|arg| arg.has_default()) {
+                        return Err(ParseError { msg: format!("Parameter `{}` without default can't follow parameters with defaults", name) });
+                    } else {
+                        None
+                    };
+                    args.push(Parameter::new(name, default))
+                }
+                TokenKind::Ellipsis => {
+                    args.push(Parameter::rest(lexer.next_or_err()?.is_identifier()?))
                 }
                 TokenKind::Comma => continue,
                 _ => return Err(ParseError { msg: "Expecting identifier or ,".to_string() })
@@ -41,15 +56,14 @@ impl Parselet for FunParselet {
         lexer.next_or_err()?
             .is_arrow()?;
 
-        let expr = parse_expression(
-            1,
-            lexer)?;
+        let expr = parse_body(lexer)?;
 
         match identifier {
             Ok(identifier) => Ok(FunStatement::rc(
                 identifier,
                 args,
                 expr,
+                doc,
             )),
             Err(_) => Ok(AnonFunction::rc(
                 args,
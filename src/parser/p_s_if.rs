@@ -1,4 +1,4 @@
-use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::parser::{Parselet, ParseError, parse_head, parse_body};
 use crate::lexer::Lexer;
 use crate::ast::Expression;
 use std::rc::Rc;
@@ -11,9 +11,17 @@ pub struct IfParselet {}
 
 impl Parselet for IfParselet {
     fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
-        let cond = parse_expression(1, lexer)?;
-        let branch = parse_expression(1, lexer)?;
-        Ok(IfStatement::rc(cond, branch))
+        let cond = parse_head(lexer)?;
+        let branch = parse_body(lexer)?;
+        // else if needs no special handling, the else branch simply parses to another if
+        let else_branch = match lexer.peek() {
+            Some(token) if token.is_else().is_ok() => {
+                lexer.next();
+                Some(parse_body(lexer)?)
+            }
+            _ => None
+        };
+        Ok(IfStatement::rc(cond, branch, else_branch))
     }
 
     fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
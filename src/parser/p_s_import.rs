@@ -0,0 +1,30 @@
+use crate::parser::{Parselet, ParseError};
+use crate::lexer::Lexer;
+use crate::lexer::tokens::TokenKind;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_import::ImportStatement;
+
+pub struct ImportParselet {}
+
+impl Parselet for ImportParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let next_token = lexer.next_or_err()?;
+        let path = match &next_token.token_kind {
+            TokenKind::Str(path) => path.clone(),
+            _ => return Err(ParseError { msg: format!("Expecting module path but {} found", next_token) }),
+        };
+        let alias = match lexer.peek() {
+            Some(token) if token.token_kind == TokenKind::As => {
+                lexer.next();
+                Some(lexer.next_or_err()?.is_identifier()?)
+            }
+            _ => None
+        };
+        Ok(ImportStatement::rc(path, alias))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse import in infix position".to_string() })
+    }
+}
\ No newline at end of file
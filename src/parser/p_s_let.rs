@@ -10,6 +10,7 @@ pub struct LetParselet {}
 impl Parselet for LetParselet {
 
     fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let doc = lexer.current().doc.clone();
         let next_token = lexer.next_or_err()?;
         let identifier = next_token.is_identifier()?;
         lexer.next_or_err()?.is_assing()?;
@@ -21,6 +22,7 @@ impl Parselet for LetParselet {
         Ok(LetStatement::rc(
             identifier,
             expr,
+            doc,
         ))
     }
 
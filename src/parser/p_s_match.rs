@@ -0,0 +1,55 @@
+use crate::parser::{Parselet, ParseError, parse_head, parse_body, get_parselet};
+use crate::lexer::Lexer;
+use crate::lexer::tokens::TokenKind;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_match::{MatchStatement, MatchPattern};
+
+pub struct MatchParselet {}
+
+impl MatchParselet {
+    /// Pattern is a literal (optionally negative number), binding identifier or `_`
+    fn parse_pattern(lexer: &mut Lexer) -> Result<MatchPattern, ParseError> {
+        let negative_number = matches!(
+            lexer.peek_nth(1).map(|token| &token.token_kind),
+            Some(TokenKind::Integer(_)) | Some(TokenKind::Float(_)));
+        let token = lexer.next_or_err()?;
+        match &token.token_kind {
+            TokenKind::Identifier(name) if name == "_" => Ok(MatchPattern::Wildcard),
+            TokenKind::Identifier(name) => Ok(MatchPattern::Binding(name.clone())),
+            TokenKind::Integer(_) | TokenKind::Float(_) | TokenKind::Str(_) |
+            TokenKind::True | TokenKind::False | TokenKind::Null => {
+                Ok(MatchPattern::Literal(get_parselet(token).nud(lexer)?))
+            }
+            TokenKind::Minus if negative_number => {
+                Ok(MatchPattern::Literal(get_parselet(token).nud(lexer)?))
+            }
+            _ => Err(ParseError { msg: format!("Expecting match pattern but {} found", token) })
+        }
+    }
+}
+
+impl Parselet for MatchParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let value = parse_head(lexer)?;
+        lexer.next_or_err()?.is_left_brace()?;
+        let mut arms = vec![];
+        loop {
+            if lexer.peek_or_err()?.is_right_brace().is_ok() {
+                lexer.next();
+                break;
+            }
+            let pattern = MatchParselet::parse_pattern(lexer)?;
+            lexer.next_or_err()?.is_arrow()?;
+            arms.push((pattern, parse_body(lexer)?));
+            if lexer.peek_or_err()?.is_comma().is_ok() {
+                lexer.next();
+            }
+        }
+        Ok(MatchStatement::rc(value, arms))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse match in infix position".to_string() })
+    }
+}
\ No newline at end of file
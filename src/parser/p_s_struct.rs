@@ -0,0 +1,71 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::lexer::tokens::Token;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_struct::StructStatement;
+use crate::ast::v_struct::StructExpression;
+
+pub struct StructParselet {}
+
+/// Struct construction is an identifier followed by `{ field:`.
+/// `{ label: while` and `{ label: for` are blocks starting with a labeled loop.
+pub fn starts_struct(tokens: [Option<&Token>; 4]) -> bool {
+    match tokens {
+        [Some(brace), Some(field), Some(colon), next] => {
+            brace.is_left_brace().is_ok() &&
+                field.is_identifier().is_ok() &&
+                colon.is_colon().is_ok() &&
+                !next.is_some_and(|next| next.is_while().is_ok() || next.is_for().is_ok())
+        }
+        _ => false,
+    }
+}
+
+impl StructParselet {
+    /// Parses `{ field: value, ... }` after the struct name
+    pub fn parse_construction(lexer: &mut Lexer, name: String) -> Result<Rc<dyn Expression>, ParseError> {
+        lexer.next_or_err()?.is_left_brace()?;
+        let mut fields = vec![];
+        loop {
+            if lexer.peek_or_err()?.is_right_brace().is_ok() {
+                lexer.next();
+                break;
+            }
+            let field = lexer.next_or_err()?.is_identifier()?;
+            lexer.next_or_err()?.is_colon()?;
+            fields.push((field, parse_expression(1, lexer)?));
+            if lexer.peek_or_err()?.is_comma().is_ok() {
+                lexer.next();
+            }
+        }
+        Ok(StructExpression::rc(name, fields))
+    }
+}
+
+impl Parselet for StructParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let name = lexer.next_or_err()?.is_identifier()?;
+        lexer.next_or_err()?.is_left_brace()?;
+        let mut fields = vec![];
+        loop {
+            if lexer.peek_or_err()?.is_right_brace().is_ok() {
+                lexer.next();
+                break;
+            }
+            let field = lexer.next_or_err()?.is_identifier()?;
+            if fields.contains(&field) {
+                return Err(ParseError { msg: format!("Field `{}` declared twice in struct {}", field, name) });
+            }
+            fields.push(field);
+            if lexer.peek_or_err()?.is_comma().is_ok() {
+                lexer.next();
+            }
+        }
+        Ok(StructStatement::rc(name, fields))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse struct in infix position".to_string() })
+    }
+}
\ No newline at end of file
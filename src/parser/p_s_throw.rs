@@ -0,0 +1,17 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_throw::ThrowStatement;
+
+pub struct ThrowParselet {}
+
+impl Parselet for ThrowParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Ok(ThrowStatement::rc(parse_expression(1, lexer)?))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse throw in infix position".to_string() })
+    }
+}
\ No newline at end of file
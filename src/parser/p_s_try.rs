@@ -0,0 +1,37 @@
+use crate::parser::{Parselet, ParseError, parse_body};
+use crate::lexer::Lexer;
+use crate::lexer::tokens::TokenKind;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_try::TryStatement;
+
+pub struct TryParselet {}
+
+impl Parselet for TryParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let body = parse_body(lexer)?;
+        let catch = match lexer.peek() {
+            Some(token) if token.token_kind == TokenKind::Catch => {
+                lexer.next();
+                let identifier = lexer.next_or_err()?.is_identifier()?;
+                Some((identifier, parse_body(lexer)?))
+            }
+            _ => None
+        };
+        let finally = match lexer.peek() {
+            Some(token) if token.token_kind == TokenKind::Finally => {
+                lexer.next();
+                Some(parse_body(lexer)?)
+            }
+            _ => None
+        };
+        if catch.is_none() && finally.is_none() {
+            return Err(ParseError { msg: "Expecting catch or finally after try".to_string() });
+        }
+        Ok(TryStatement::rc(body, catch, finally))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse try in infix position".to_string() })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::parser::{Parselet, ParseError, parse_head, parse_body};
+use crate::lexer::Lexer;
+use crate::ast::Expression;
+use std::rc::Rc;
+use crate::ast::s_while::WhileStatement;
+
+pub struct WhileParselet {}
+
+impl WhileParselet {
+    /// Parses rest of the while statement after `while` token.
+    /// Labeled loops (`outer: while ...`) are detected in IdentifierParselet.
+    pub fn parse_while(lexer: &mut Lexer, label: Option<String>) -> Result<Rc<dyn Expression>, ParseError> {
+        let condition = parse_head(lexer)?;
+        let body = parse_body(lexer)?;
+        Ok(WhileStatement::rc(label, condition, body))
+    }
+}
+
+impl Parselet for WhileParselet {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        WhileParselet::parse_while(lexer, None)
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse while in infix position".to_string() })
+    }
+}
\ No newline at end of file
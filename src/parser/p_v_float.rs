@@ -0,0 +1,20 @@
+use crate::parser::{Parselet, ParseError};
+use crate::lexer::Lexer;
+use crate::ast::v_float::FloatExpression;
+use crate::ast::Expression;
+use std::rc::Rc;
+
+pub struct FloatParselet {
+    pub value: f64,
+}
+
+impl Parselet for FloatParselet {
+
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        Ok(FloatExpression::rc(self.value))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err(ParseError { msg: "Can't parse float in LED position".to_string() })
+    }
+}
@@ -2,6 +2,9 @@ use crate::parser::{Parselet, ParseError};
 use crate::lexer::Lexer;
 use crate::ast::{Expression};
 use crate::ast::e_identifier::IdentifierExpression;
+use crate::parser::p_s_while::WhileParselet;
+use crate::parser::p_s_for::ForParselet;
+use crate::parser::p_s_struct::{StructParselet, starts_struct};
 use std::rc::Rc;
 
 pub struct IdentifierParselet {
@@ -10,7 +13,24 @@ pub struct IdentifierParselet {
 
 impl Parselet for IdentifierParselet {
 
-    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+    fn nud(&self, lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        // Identifier followed by `: while` or `: for` is a loop label
+        if let (Some(colon), Some(loop_token)) = (lexer.peek(), lexer.peek_nth(1)) {
+            if colon.is_colon().is_ok() && loop_token.is_while().is_ok() {
+                lexer.next();
+                lexer.next();
+                return WhileParselet::parse_while(lexer, Some(self.value.clone()));
+            }
+            if colon.is_colon().is_ok() && loop_token.is_for().is_ok() {
+                lexer.next();
+                lexer.next();
+                return ForParselet::parse_for(lexer, Some(self.value.clone()));
+            }
+        }
+        // Identifier followed by `{ field:` constructs a struct, unless the brace starts a body
+        if lexer.structs_allowed() && starts_struct([lexer.peek(), lexer.peek_nth(1), lexer.peek_nth(2), lexer.peek_nth(3)]) {
+            return StructParselet::parse_construction(lexer, self.value.clone());
+        }
         Ok(IdentifierExpression::rc(self.value.clone()))
     }
 
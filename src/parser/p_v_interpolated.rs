@@ -0,0 +1,38 @@
+use crate::parser::{Parselet, ParseError, parse_expression};
+use crate::lexer::Lexer;
+use crate::lexer::tokens::StrPart;
+use crate::ast::Expression;
+use crate::ast::v_string::StringExpression;
+use crate::ast::e_interpolated::InterpolatedStringExpression;
+use std::rc::Rc;
+
+pub struct InterpolatedStringParselet {
+    pub parts: Vec<StrPart>,
+}
+
+impl Parselet for InterpolatedStringParselet {
+
+    fn nud(&self, _lexer: &mut Lexer) -> Result<Rc<dyn Expression>, ParseError> {
+        let mut parts: Vec<Rc<dyn Expression>> = vec![];
+        for part in &self.parts {
+            match part {
+                StrPart::Literal(value) => parts.push(StringExpression::rc(value.clone())),
+                StrPart::Expression(tokens) => {
+                    // Each expression has its own token stream
+                    let mut lexer = Lexer::from_tokens(tokens.clone());
+                    parts.push(parse_expression(0, &mut lexer)?);
+                    if let Some(token) = lexer.peek() {
+                        return Err(ParseError {
+                            msg: format!("Unexpected {} in string interpolation", token)
+                        });
+                    }
+                }
+            }
+        }
+        Ok(InterpolatedStringExpression::rc(parts))
+    }
+
+    fn led(&self, _lexer: &mut Lexer, _left: Rc<dyn Expression>) -> Result<Rc<dyn Expression>, ParseError> {
+        Err( ParseError { msg: "Can't parse string in LED position".to_string() } )
+    }
+}
\ No newline at end of file